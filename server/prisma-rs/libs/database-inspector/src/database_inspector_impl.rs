@@ -4,30 +4,103 @@ use rusqlite::{Connection, Result, NO_PARAMS};
 
 pub struct DatabaseInspectorImpl {
     connection: Connection,
+    options: IntrospectionOptions,
+}
+
+/// Opt-in behavior of the SQLite inspector.
+#[derive(Debug, Default, Clone)]
+pub struct IntrospectionOptions {
+    /// Report `CHECK (<column> IN ('a', 'b'))` constraints as enums named `<table>_<column>`. The constraints
+    /// themselves are still reported as check constraints.
+    pub enums_from_check_constraints: bool,
+    /// Read the current counters of AUTOINCREMENT tables from `sqlite_sequence` into the `last_value` of their
+    /// sequences.
+    pub autoincrement_counters: bool,
+    /// Report the shadow tables virtual tables keep their data in, e.g. `docs_data` of the fts5 table `docs`, as
+    /// tables.
+    pub include_shadow_tables: bool,
 }
 
 impl DatabaseInspector for DatabaseInspectorImpl {
     fn introspect(&self, schema: &String) -> DatabaseSchema {
+        let (create_tables, virtual_tables) = self.get_table_definitions(schema);
+        let tables: Vec<Table> = create_tables
+            .iter()
+            .map(|(table, create_table)| self.get_table(schema, table, create_table))
+            .collect();
+        // The counters of AUTOINCREMENT tables are reported as the sequences of their primary key columns.
+        let sequences = tables
+            .iter()
+            .flat_map(|t| t.columns.iter().filter_map(|c| c.sequence.clone()))
+            .collect();
+
         DatabaseSchema {
-            tables: self
-                .get_table_names(schema)
+            tables,
+            views: self
+                .get_view_names(schema)
                 .into_iter()
-                .map(|t| self.get_table(schema, &t))
+                .map(|v| self.get_view(schema, &v))
                 .collect(),
+            // SQLite has no enum types, they can only be emulated with check constraints.
+            enums: if self.options.enums_from_check_constraints {
+                create_tables
+                    .iter()
+                    .filter_map(|(table, create_table)| create_table.as_ref().map(|c| check_constraint_enums(table, c)))
+                    .flatten()
+                    .map(|(_, enum_)| enum_)
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            sequences,
+            virtual_tables,
         }
     }
 }
 
 impl DatabaseInspectorImpl {
     pub fn new(connection: Connection) -> DatabaseInspectorImpl {
-        DatabaseInspectorImpl { connection }
+        DatabaseInspectorImpl::with_options(connection, IntrospectionOptions::default())
     }
 
-    fn get_table_names(&self, schema: &String) -> Vec<String> {
+    pub fn with_options(connection: Connection, options: IntrospectionOptions) -> DatabaseInspectorImpl {
+        DatabaseInspectorImpl { connection, options }
+    }
+
+    /// Splits the tables of `sqlite_master` into the ordinary tables, along with their parsed CREATE statements, and
+    /// the virtual tables. Virtual tables and their shadow tables are listed as tables as well.
+    fn get_table_definitions(
+        &self,
+        schema: &String,
+    ) -> (Vec<(String, Option<sqlite_ddl::CreateTable>)>, Vec<VirtualTable>) {
+        let mut create_tables = Vec::new();
+        let mut virtual_tables = Vec::new();
+
+        for (name, sql) in self.query_table_definitions(schema) {
+            match sql.as_ref().and_then(|sql| sqlite_ddl::parse_create_virtual_table(sql)) {
+                Some(create_virtual_table) => virtual_tables.push(VirtualTable {
+                    name,
+                    module: create_virtual_table.module,
+                    arguments: create_virtual_table.arguments,
+                }),
+                None if name == "sqlite_sequence" => (),
+                None => create_tables.push((name, sql.and_then(|sql| sqlite_ddl::parse_create_table(&sql)))),
+            }
+        }
+        // A shadow table may be listed before its virtual table.
+        if !self.options.include_shadow_tables {
+            create_tables.retain(|(name, _)| !virtual_tables.iter().any(|v| is_shadow_table(v, name)));
+        }
+
+        (create_tables, virtual_tables)
+    }
+
+    /// The names and CREATE statements of all tables, including virtual tables and their shadow tables.
+    fn query_table_definitions(&self, schema: &String) -> Vec<(String, Option<String>)> {
         let sql = format!(
             "
             SELECT
-                name
+                name, sql
             FROM
                 {}.sqlite_master
             WHERE
@@ -40,75 +113,333 @@ impl DatabaseInspectorImpl {
         let mut rows = stmt.query(NO_PARAMS).unwrap();
         let mut result = Vec::new();
 
+        while let Some(row_result) = rows.next() {
+            let row = row_result.unwrap();
+            result.push((row.get("name"), row.get("sql")));
+        }
+
+        result
+    }
+
+    fn get_view_names(&self, schema: &String) -> Vec<String> {
+        let sql = format!(r#"SELECT name FROM "{}".sqlite_master WHERE type='view'"#, schema);
+        let mut stmt = self.connection.prepare_cached(&sql).unwrap();
+        let mut rows = stmt.query(NO_PARAMS).unwrap();
+        let mut result = Vec::new();
+
         while let Some(row) = rows.next() {
-            let name: String = row.unwrap().get("name");
-            if name != "sqlite_sequence" {
-                result.push(name);
-            }
+            result.push(row.unwrap().get("name"));
         }
 
         result
     }
 
-    fn get_table(&self, schema: &String, table: &String) -> Table {
+    fn get_view(&self, schema: &String, view: &String) -> View {
+        View {
+            name: view.to_string(),
+            definition: self.get_sql(schema, view),
+            columns: self
+                .get_columns(schema, view)
+                .into_iter()
+                .map(|c| ViewColumn {
+                    tpe: known_column_type(&c.tpe),
+                    is_required: c.is_required,
+                    name: c.name,
+                    declared_type: c.tpe,
+                })
+                .collect(),
+            triggers: self.get_triggers(schema, view),
+        }
+    }
+
+    fn get_table(&self, schema: &String, table: &String, create_table: &Option<sqlite_ddl::CreateTable>) -> Table {
         let introspected_columns = self.get_columns(&schema, &table);
         let introspected_foreign_keys = self.get_foreign_constraints(&schema, &table);
-        // let _index = get_index(&schema, &table);
-        // let _seq = get_sequence(&schema, &table);
+        let primary_key = primary_key(&introspected_columns, create_table);
+        let mut columns = convert_introspected_columns(introspected_columns, introspected_foreign_keys, create_table);
+
+        if self.options.autoincrement_counters {
+            for sequence in columns.iter_mut().filter_map(|c| c.sequence.as_mut()) {
+                sequence.last_value = self.get_sequence_value(schema, &sequence.name);
+            }
+        }
+
+        if let (true, Some(create_table)) = (self.options.enums_from_check_constraints, create_table) {
+            for (column_name, enum_) in check_constraint_enums(table, create_table) {
+                if let Some(column) = columns.iter_mut().find(|c| c.name.eq_ignore_ascii_case(&column_name)) {
+                    column.tpe.family = ColumnTypeFamily::Enum(enum_.name);
+                }
+            }
+        }
 
         Table {
             name: table.to_string(),
-            columns: convert_introspected_columns(introspected_columns, introspected_foreign_keys),
-            indexes: Vec::new(),
+            primary_key,
+            columns,
+            indexes: self.get_indexes(schema, table),
+            unique_constraints: self.get_unique_constraints(schema, table, create_table),
+            check_constraints: create_table.as_ref().map(check_constraints).unwrap_or_default(),
+            // SQLite keeps the temporary tables of a connection in the `temp` schema.
+            persistence: if schema == "temp" {
+                TablePersistence::Temporary
+            } else {
+                TablePersistence::Permanent
+            },
+            without_rowid: create_table.as_ref().map(|c| c.without_rowid).unwrap_or(false),
+            triggers: self.get_triggers(schema, table),
         }
     }
 
-    fn get_columns(&self, schema: &String, table: &String) -> Vec<IntrospectedColumn> {
-        let sql = format!(r#"Pragma "{}".table_info ("{}")"#, schema, table);
+    fn get_triggers(&self, schema: &String, table: &String) -> Vec<Trigger> {
+        let sql = format!(
+            r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='trigger' AND tbl_name = ? COLLATE NOCASE"#,
+            schema
+        );
         let mut stmt = self.connection.prepare_cached(&sql).unwrap();
-        let mut rows = stmt.query(NO_PARAMS).unwrap();
+        let mut rows = stmt.query(&[table]).unwrap();
+        let mut result = Vec::new();
+
+        while let Some(row_result) = rows.next() {
+            let row = row_result.unwrap();
+            let name: String = row.get("name");
+            let sql: String = row.get("sql");
+            if let Some(create_trigger) = sqlite_ddl::parse_create_trigger(&sql) {
+                result.push(trigger(name, create_trigger));
+            }
+        }
+
+        result
+    }
+
+    fn get_columns(&self, schema: &String, table: &String) -> Vec<IntrospectedColumn> {
+        // table_info leaves out generated columns, table_xinfo lists them as hidden. SQLite versions before 3.26
+        // do not know table_xinfo and return no rows for it.
+        let columns = self.query_columns(
+            &format!(r#"Pragma "{}".table_xinfo ("{}")"#, schema, escape_identifier(table)),
+            table,
+        );
+        if columns.is_empty() {
+            self.query_columns(
+                &format!(r#"Pragma "{}".table_info ("{}")"#, schema, escape_identifier(table)),
+                table,
+            )
+        } else {
+            columns
+        }
+    }
+
+    fn query_columns(&self, sql: &str, table: &String) -> Vec<IntrospectedColumn> {
+        // SQLite fails to list the columns of a view whose base table was dropped. The view is still reported,
+        // just without columns.
+        let mut stmt = match self.connection.prepare_cached(&sql) {
+            Ok(stmt) => stmt,
+            Err(_) => return Vec::new(),
+        };
+        let has_hidden_column = stmt.column_index("hidden").is_ok();
+        let mut rows = match stmt.query(NO_PARAMS) {
+            Ok(rows) => rows,
+            Err(_) => return Vec::new(),
+        };
         let mut result = Vec::new();
 
         while let Some(row_result) = rows.next() {
             let row = row_result.unwrap();
+            let hidden: i64 = if has_hidden_column { row.get("hidden") } else { 0 };
+            // Hidden columns of virtual tables. Generated columns are reported as 2 (virtual) and 3 (stored).
+            if hidden == 1 {
+                continue;
+            }
             result.push(IntrospectedColumn {
                 name: row.get("name"),
                 table: table.to_string(),
                 tpe: row.get("type"),
                 is_required: row.get("notnull"),
                 default: row.get("dflt_value"),
+                primary_key_position: row.get("pk"),
+                position: row.get("cid"),
             });
         }
 
+        result.sort_by_key(|c| c.position);
         result
     }
 
     fn get_foreign_constraints(&self, schema: &String, table: &String) -> Vec<IntrospectedForeignKey> {
-        let sql = format!(r#"Pragma "{}".foreign_key_list("{}");"#, schema, table);
+        let sql = format!(
+            r#"Pragma "{}".foreign_key_list("{}");"#,
+            schema,
+            escape_identifier(table)
+        );
         let mut stmt = self.connection.prepare_cached(&sql).unwrap();
         let mut rows = stmt.query(NO_PARAMS).unwrap();
         let mut result = Vec::new();
 
         while let Some(row_result) = rows.next() {
             let row = row_result.unwrap();
+            let position: i64 = row.get("seq");
+            let referenced_table: String = row.get("table");
+            let referenced_column: Option<String> = row.get("to");
+            // `REFERENCES a` without a column list references the primary key of `a`. If `a` has none, SQLite
+            // rejects the foreign key as soon as it is used, so it is left out.
+            let referenced_column =
+                match referenced_column.or_else(|| self.get_primary_key_column(schema, &referenced_table, position)) {
+                    Some(column) => column,
+                    None => continue,
+                };
             result.push(IntrospectedForeignKey {
                 name: "".to_string(),
+                id: row.get("id"),
+                position,
                 table: table.to_string(),
                 column: row.get("from"),
-                referenced_table: row.get("table"),
-                referenced_column: row.get("to"),
+                referenced_table,
+                referenced_column,
+                on_delete_action: row.get("on_delete"),
+                on_update_action: row.get("on_update"),
+            });
+        }
+
+        // The pragma lists one row per referencing column. Keep the columns of a composite foreign key in
+        // declaration order instead of relying on the order SQLite happens to return them in.
+        result.sort_by_key(|fk| (fk.id, fk.position));
+        result
+    }
+
+    /// The column at the 0-based `position` of the primary key of `table`.
+    fn get_primary_key_column(&self, schema: &String, table: &String, position: i64) -> Option<String> {
+        let mut primary_key_columns: Vec<IntrospectedColumn> = self
+            .get_columns(schema, table)
+            .into_iter()
+            .filter(|c| c.primary_key_position > 0)
+            .collect();
+        primary_key_columns.sort_by_key(|c| c.primary_key_position);
+
+        primary_key_columns.into_iter().nth(position as usize).map(|c| c.name)
+    }
+
+    /// SQLite creates `sqlite_sequence` along with the first AUTOINCREMENT table, and adds a row for a table on its
+    /// first insert.
+    fn get_sequence_value(&self, schema: &String, table: &String) -> Option<i64> {
+        let sql = format!(r#"SELECT seq FROM "{}".sqlite_sequence WHERE name = ?"#, schema);
+        let mut stmt = self.connection.prepare_cached(&sql).ok()?;
+        let mut rows = stmt.query(&[table]).unwrap();
+
+        rows.next().map(|row| row.unwrap().get("seq"))
+    }
+
+    fn get_indexes(&self, schema: &String, table: &str) -> Vec<Index> {
+        let sql = format!(r#"Pragma "{}".index_list("{}");"#, schema, escape_identifier(table));
+        let mut stmt = self.connection.prepare_cached(&sql).unwrap();
+        let mut rows = stmt.query(NO_PARAMS).unwrap();
+        let mut result = Vec::new();
+
+        while let Some(row_result) = rows.next() {
+            let row = row_result.unwrap();
+            let name: String = row.get("name");
+            let origin: String = row.get("origin");
+            let is_partial: bool = row.get("partial");
+
+            // SQLite backs PRIMARY KEY and UNIQUE constraints with internal `sqlite_autoindex_*` indexes.
+            // Those are reported as the primary key and unique constraints of the table instead.
+            if origin != "c" {
+                continue;
+            }
+            let sql = self.get_sql(schema, &name);
+            result.push(Index {
+                columns: self.get_index_columns(schema, &name, &sql),
+                unique: row.get("unique"),
+                predicate: match (is_partial, &sql) {
+                    (true, Some(sql)) => sqlite_ddl::index_predicate(sql),
+                    _ => None,
+                },
+                name,
             });
         }
 
         result
     }
 
-    fn get_sequence(&self, _schema: &String, _table: &String) -> Sequence {
-        unimplemented!()
+    fn get_unique_constraints(
+        &self,
+        schema: &String,
+        table: &str,
+        create_table: &Option<sqlite_ddl::CreateTable>,
+    ) -> Vec<UniqueConstraint> {
+        let sql = format!(r#"Pragma "{}".index_list("{}");"#, schema, escape_identifier(table));
+        let mut stmt = self.connection.prepare_cached(&sql).unwrap();
+        let mut rows = stmt.query(NO_PARAMS).unwrap();
+        let mut result = Vec::new();
+
+        while let Some(row_result) = rows.next() {
+            let row = row_result.unwrap();
+            let name: String = row.get("name");
+            let origin: String = row.get("origin");
+
+            if origin == "u" {
+                let columns: Vec<String> = self
+                    .get_index_columns(schema, &name, &None)
+                    .iter()
+                    .filter_map(|c| c.column_name())
+                    .collect();
+                result.push(UniqueConstraint {
+                    // The auto-index is named by SQLite, the constraint name is only found in the DDL.
+                    name: create_table
+                        .as_ref()
+                        .and_then(|create_table| constraint_name(create_table, "UNIQUE", &columns)),
+                    columns,
+                });
+            }
+        }
+
+        result
+    }
+
+    fn get_sql(&self, schema: &String, name: &String) -> Option<String> {
+        let sql = format!(r#"SELECT sql FROM "{}".sqlite_master WHERE name = ?"#, schema);
+        let mut stmt = self.connection.prepare_cached(&sql).unwrap();
+        let mut rows = stmt.query(&[name]).unwrap();
+
+        rows.next().and_then(|row| row.unwrap().get("sql"))
     }
 
-    fn get_index(&self, _schema: &String, _table: &String) -> Index {
-        unimplemented!()
+    fn get_index_columns(&self, schema: &String, index: &str, index_sql: &Option<String>) -> Vec<IndexColumn> {
+        let sql = format!(r#"Pragma "{}".index_xinfo("{}");"#, schema, escape_identifier(index));
+        let mut stmt = self.connection.prepare_cached(&sql).unwrap();
+        let mut rows = stmt.query(NO_PARAMS).unwrap();
+        let definitions = index_sql
+            .as_ref()
+            .map(|sql| sqlite_ddl::index_definitions(sql))
+            .unwrap_or_default();
+        let mut result = Vec::new();
+
+        while let Some(row_result) = rows.next() {
+            let row = row_result.unwrap();
+            let position: i64 = row.get("seqno");
+            let cid: i64 = row.get("cid");
+            let is_key: bool = row.get("key");
+            let name: Option<String> = row.get("name");
+            let is_descending: bool = row.get("desc");
+
+            // index_xinfo also lists the auxiliary columns (e.g. the rowid) stored with every index entry.
+            if !is_key {
+                continue;
+            }
+            // A cid of -2 marks an expression, which only the CREATE INDEX statement knows about.
+            let value = match (name, cid) {
+                (Some(name), _) => IndexedValue::Column(name),
+                (None, -2) => match definitions.get(position as usize) {
+                    Some(expression) => IndexedValue::Expression(expression.clone()),
+                    None => continue,
+                },
+                (None, _) => continue,
+            };
+            result.push(IndexColumn {
+                value,
+                sort_order: if is_descending { SortOrder::Desc } else { SortOrder::Asc },
+            });
+        }
+
+        result
     }
 
     // fn query<F>(&self, schema: &String, parse: F) ->
@@ -117,40 +448,472 @@ impl DatabaseInspectorImpl {
 fn convert_introspected_columns(
     columns: Vec<IntrospectedColumn>,
     foreign_keys: Vec<IntrospectedForeignKey>,
+    create_table: &Option<sqlite_ddl::CreateTable>,
 ) -> Vec<Column> {
     columns
         .iter()
         .map(|c| {
+            let is_rowid_alias = is_rowid_alias(c, &columns, create_table);
             let foreign_key = foreign_keys
                 .iter()
                 .find(|fk| fk.column == c.name && fk.table == c.table)
                 .map(|fk| ForeignKey {
                     table: fk.referenced_table.clone(),
                     column: fk.referenced_column.clone(),
+                    constraint_name: create_table
+                        .as_ref()
+                        .and_then(|create_table| foreign_key_constraint_name(create_table, fk, &foreign_keys)),
+                    on_delete_action: foreign_key_action(&fk.on_delete_action),
+                    on_update_action: foreign_key_action(&fk.on_update_action),
                 });
+            let column_definition = create_table
+                .as_ref()
+                .and_then(|create_table| create_table.column(&c.name));
+            let tpe = column_type(c);
+            let default = c.default.as_ref().map(|default| default_value(default, &tpe.family));
             Column {
                 name: c.name.clone(),
-                tpe: column_type(c),
+                tpe,
                 is_required: c.is_required,
                 foreign_key: foreign_key,
-                sequence: None,
+                sequence: column_definition
+                    .filter(|_| is_rowid_alias)
+                    .and_then(|definition| autoincrement_sequence(c, definition)),
+                generated: column_definition.and_then(generated_column),
+                collation: column_definition.and_then(collation),
+                default,
+                ordinal_position: Some(c.position as u32 + 1),
+                auto_increment: is_rowid_alias,
             }
         })
         .collect()
 }
 
+fn trigger(name: String, create_trigger: sqlite_ddl::CreateTrigger) -> Trigger {
+    Trigger {
+        name,
+        timing: match create_trigger.timing.as_ref().map(|t| t.as_ref()) {
+            Some("AFTER") => TriggerTiming::After,
+            Some("INSTEAD") => TriggerTiming::InsteadOf,
+            _ => TriggerTiming::Before,
+        },
+        events: vec![match create_trigger.event.as_ref() {
+            "DELETE" => TriggerEvent::Delete,
+            "INSERT" => TriggerEvent::Insert,
+            _ => TriggerEvent::Update,
+        }],
+        condition: create_trigger.condition,
+        body: create_trigger.body,
+    }
+}
+
+/// Virtual tables of the built-in full-text search and r-tree modules store their data in ordinary tables named
+/// after them, e.g. `docs_content` for the fts5 table `docs`.
+fn is_shadow_table(virtual_table: &VirtualTable, name: &str) -> bool {
+    let suffixes: &[&str] = match virtual_table.module.to_lowercase().as_ref() {
+        "fts5" => &["data", "idx", "content", "docsize", "config"],
+        "fts3" | "fts4" => &["content", "segments", "segdir", "docsize", "stat"],
+        "rtree" | "rtree_i32" => &["node", "parent", "rowid"],
+        _ => &[],
+    };
+
+    suffixes
+        .iter()
+        .any(|suffix| name == format!("{}_{}", virtual_table.name, suffix))
+}
+
+/// Whether the column is an alias of the rowid, which SQLite assigns on insert: the only primary key column of a
+/// rowid table, declared exactly as `INTEGER`. `INTEGER PRIMARY KEY DESC` column constraints are a documented
+/// exception.
+fn is_rowid_alias(
+    column: &IntrospectedColumn,
+    columns: &[IntrospectedColumn],
+    create_table: &Option<sqlite_ddl::CreateTable>,
+) -> bool {
+    let is_only_primary_key_column =
+        column.primary_key_position == 1 && columns.iter().filter(|c| c.primary_key_position > 0).count() == 1;
+    let (without_rowid, is_descending) = match create_table {
+        Some(create_table) => (
+            create_table.without_rowid,
+            create_table
+                .column(&column.name)
+                .map(|c| c.constraints.iter().any(|c| c.is("PRIMARY") && c.has_keyword("DESC")))
+                .unwrap_or(false),
+        ),
+        None => (false, false),
+    };
+
+    is_only_primary_key_column && column.tpe.eq_ignore_ascii_case("INTEGER") && !without_rowid && !is_descending
+}
+
+/// `AUTOINCREMENT` makes SQLite keep the largest rowid ever used in `sqlite_sequence`, so that rowids of deleted
+/// rows are never reused. The counter is reported as a sequence named after the table.
+fn autoincrement_sequence(column: &IntrospectedColumn, definition: &sqlite_ddl::ColumnDefinition) -> Option<Sequence> {
+    if !definition
+        .constraints
+        .iter()
+        .any(|c| c.is("PRIMARY") && c.has_keyword("AUTOINCREMENT"))
+    {
+        return None;
+    }
+
+    Some(Sequence {
+        name: column.table.clone(),
+        last_value: None,
+        owned_by: Some(SequenceOwner {
+            table: column.table.clone(),
+            column: column.name.clone(),
+        }),
+    })
+}
+
+/// The declared name of the table or column constraint of the given kind covering exactly `columns`. Identifiers are
+/// case insensitive, so the constraint may spell the columns differently than the pragmas report them.
+fn constraint_name(create_table: &sqlite_ddl::CreateTable, keyword: &str, columns: &[String]) -> Option<String> {
+    let covers_columns = |column_list: Vec<String>| {
+        column_list.len() == columns.len() && column_list.iter().zip(columns).all(|(a, b)| a.eq_ignore_ascii_case(b))
+    };
+    let table_constraint = create_table
+        .constraints
+        .iter()
+        .find(|c| c.is(keyword) && covers_columns(c.column_list()));
+    let column_constraint = match columns {
+        [column] => create_table
+            .column(column)
+            .and_then(|c| c.constraints.iter().find(|c| c.is(keyword))),
+        _ => None,
+    };
+
+    table_constraint.or(column_constraint).and_then(|c| c.name.clone())
+}
+
+/// SQLite has no pragma for CHECK constraints, so they are taken from the CREATE TABLE statement.
+/// The declared name of a foreign key, from either its `FOREIGN KEY` table constraint or its `REFERENCES` column
+/// constraint.
+fn foreign_key_constraint_name(
+    create_table: &sqlite_ddl::CreateTable,
+    foreign_key: &IntrospectedForeignKey,
+    foreign_keys: &[IntrospectedForeignKey],
+) -> Option<String> {
+    let columns: Vec<String> = foreign_keys
+        .iter()
+        .filter(|fk| fk.id == foreign_key.id)
+        .map(|fk| fk.column.clone())
+        .collect();
+
+    constraint_name(create_table, "FOREIGN", &columns).or_else(|| constraint_name(create_table, "REFERENCES", &columns))
+}
+
+fn check_constraints(create_table: &sqlite_ddl::CreateTable) -> Vec<CheckConstraint> {
+    let column_checks = create_table.columns.iter().flat_map(|column| {
+        column
+            .constraints
+            .iter()
+            .filter(|c| c.is("CHECK"))
+            .map(move |c| (c, vec![column.name.clone()]))
+    });
+    let table_checks = create_table.constraints.iter().filter(|c| c.is("CHECK")).map(|c| {
+        let identifiers = c.identifiers();
+        let columns = create_table
+            .columns
+            .iter()
+            .filter(|column| identifiers.iter().any(|i| i.eq_ignore_ascii_case(&column.name)))
+            .map(|column| column.name.clone())
+            .collect();
+        (c, columns)
+    });
+
+    column_checks
+        .chain(table_checks)
+        .filter_map(|(constraint, columns)| {
+            constraint.parenthesized().map(|expression| CheckConstraint {
+                name: constraint.name.clone(),
+                expression,
+                columns,
+            })
+        })
+        .collect()
+}
+
+/// Enums emulated with `CHECK (<column> IN (...))` constraints, either on the column itself or on the table,
+/// along with the name of the column they are emulated for.
+fn check_constraint_enums(table: &str, create_table: &sqlite_ddl::CreateTable) -> Vec<(String, Enum)> {
+    let column_checks = create_table.columns.iter().flat_map(|column| {
+        column
+            .constraints
+            .iter()
+            .filter_map(|c| c.in_list())
+            .filter(move |(name, _)| name.eq_ignore_ascii_case(&column.name))
+            .map(move |(_, values)| (column, values))
+    });
+    let table_checks = create_table
+        .constraints
+        .iter()
+        .filter_map(|c| c.in_list())
+        .filter_map(|(name, values)| create_table.column(&name).map(|column| (column, values)));
+    let mut result: Vec<(String, Enum)> = Vec::new();
+
+    for (column, values) in column_checks.chain(table_checks) {
+        if !result.iter().any(|(name, _)| name == &column.name) {
+            let enum_ = Enum {
+                name: format!("{}_{}", table, column.name),
+                values,
+            };
+            result.push((column.name.clone(), enum_));
+        }
+    }
+
+    result
+}
+
+/// SQLite exposes the expression of a generated column only in the DDL:
+/// `[GENERATED ALWAYS] AS (<expression>) [VIRTUAL | STORED]`.
+fn generated_column(column: &sqlite_ddl::ColumnDefinition) -> Option<GeneratedColumn> {
+    column
+        .constraints
+        .iter()
+        .find(|c| c.is("GENERATED") || c.is("AS"))
+        .and_then(|c| {
+            c.parenthesized().map(|expression| GeneratedColumn {
+                expression,
+                stored: c.ends_with("STORED"),
+            })
+        })
+}
+
+/// The `COLLATE <name>` clause of a column. `BINARY` is the default collation of SQLite.
+fn collation(column: &sqlite_ddl::ColumnDefinition) -> Option<String> {
+    column
+        .constraints
+        .iter()
+        .find(|c| c.is("COLLATE"))
+        .and_then(|c| c.identifiers().get(1).cloned())
+        .filter(|name| !name.eq_ignore_ascii_case("BINARY"))
+}
+
+/// Classifies a default as SQLite reports it, i.e. as written in the CREATE TABLE statement. Apart from literals
+/// and the `CURRENT_*` keywords, SQLite only accepts parenthesized expressions as defaults.
+fn default_value(default: &str, family: &ColumnTypeFamily) -> DefaultValue {
+    let default = default.trim();
+    let upper = default.to_uppercase();
+
+    // Booleans are stored as integers, but may be declared with keywords or as strings as well.
+    if family == &ColumnTypeFamily::Boolean {
+        match upper.trim_matches('\'') {
+            "1" | "TRUE" | "T" => return DefaultValue::Boolean(true),
+            "0" | "FALSE" | "F" => return DefaultValue::Boolean(false),
+            _ => (),
+        }
+    }
+
+    if upper == "NULL" {
+        DefaultValue::Null
+    } else if let Some(string) = unquote(default, '\'').or_else(|| unquote(default, '"')) {
+        DefaultValue::Literal(string)
+    } else if let Some(bytes) = blob_literal(default) {
+        DefaultValue::Binary(bytes)
+    } else if default.parse::<f64>().is_ok() {
+        DefaultValue::Literal(default.to_string())
+    } else if let Some(value) = hexadecimal_literal(default) {
+        DefaultValue::Literal(value.to_string())
+    } else if let Some(precision) = now_default(default) {
+        DefaultValue::Now(precision)
+    } else if is_uuid_default(default) {
+        DefaultValue::Uuid
+    } else if default.starts_with('(') && default.ends_with(')') {
+        DefaultValue::Expression(default[1..default.len() - 1].trim().to_string())
+    } else {
+        DefaultValue::Expression(default.to_string())
+    }
+}
+
+/// Doubles the quotes in a name, so that it can be put between double quotes, e.g. in the argument of a pragma.
+fn escape_identifier(name: &str) -> String {
+    name.replace('"', r#""""#)
+}
+
+/// Removes one level of SQL quoting, e.g. `'it''s'` becomes `it's`. None if the value is not a single quoted string.
+fn unquote(value: &str, quote: char) -> Option<String> {
+    if value.len() < 2 || !value.starts_with(quote) || !value.ends_with(quote) {
+        return None;
+    }
+    let mut result = String::new();
+    let mut chars = value[1..value.len() - 1].chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == quote {
+            // Inside the quotes a quote is only allowed doubled, anything else is e.g. `'a' || 'b'`.
+            match chars.next() {
+                Some(next) if next == quote => (),
+                _ => return None,
+            }
+        }
+        result.push(c);
+    }
+
+    Some(result)
+}
+
+/// Decodes a blob literal like `x'DEADBEEF'`.
+fn blob_literal(value: &str) -> Option<Vec<u8>> {
+    if !value.starts_with("x'") && !value.starts_with("X'") {
+        return None;
+    }
+    let digits = unquote(&value[1..], '\'')?;
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| digits.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+        .collect()
+}
+
+/// Parses a hexadecimal integer literal like `0x1F`, optionally signed.
+fn hexadecimal_literal(value: &str) -> Option<i64> {
+    let (sign, digits) = match value.chars().next() {
+        Some('-') => (-1, &value[1..]),
+        Some('+') => (1, &value[1..]),
+        _ => (1, value),
+    };
+    if !digits.starts_with("0x") && !digits.starts_with("0X") {
+        return None;
+    }
+
+    i64::from_str_radix(&digits[2..], 16).ok().map(|value| sign * value)
+}
+
+/// Recognizes the spellings of "the time of the insert", ignoring case, whitespace and enclosing parentheses.
+/// Returns the fractional seconds precision, if one is given. `CURRENT_DATE` and `CURRENT_TIME` only cover part of the
+/// timestamp, so they stay expressions.
+fn now_default(default: &str) -> Option<Option<u32>> {
+    let mut default: String = default
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+        .replace('"', "'");
+    while default.starts_with('(') && default.ends_with(')') {
+        default = default[1..default.len() - 1].to_string();
+    }
+
+    match default.as_ref() {
+        "current_timestamp" | "current_timestamp()" | "now()" | "datetime('now')" => Some(None),
+        _ => {
+            let (name, parameters) = split_declared_type(&default);
+            match (name, parameters.as_slice()) {
+                ("current_timestamp", [precision]) | ("now", [precision]) => Some(Some(*precision)),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Recognizes calls of the usual UUID generating functions, e.g. `(uuid())` of the SQLite uuid extension.
+fn is_uuid_default(default: &str) -> bool {
+    let mut default: String = default
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    while default.starts_with('(') && default.ends_with(')') {
+        default = default[1..default.len() - 1].to_string();
+    }
+    // The functions may be qualified with the schema of the extension providing them.
+    let function = default.rsplit('.').next().unwrap_or("");
+
+    matches!(function, "uuid()" | "gen_random_uuid()" | "uuid_generate_v4()")
+}
+
+fn primary_key(columns: &[IntrospectedColumn], create_table: &Option<sqlite_ddl::CreateTable>) -> Option<PrimaryKey> {
+    let mut primary_key_columns: Vec<&IntrospectedColumn> =
+        columns.iter().filter(|c| c.primary_key_position > 0).collect();
+    primary_key_columns.sort_by_key(|c| c.primary_key_position);
+    let columns: Vec<String> = primary_key_columns.iter().map(|c| c.name.clone()).collect();
+
+    if columns.is_empty() {
+        None
+    } else {
+        Some(PrimaryKey {
+            constraint_name: create_table
+                .as_ref()
+                .and_then(|create_table| constraint_name(create_table, "PRIMARY", &columns)),
+            columns,
+        })
+    }
+}
+
 fn column_type(column: &IntrospectedColumn) -> ColumnType {
-    match column.tpe.as_ref() {
-        "INTEGER" => ColumnType::Int,
-        "REAL" => ColumnType::Float,
-        "BOOLEAN" => ColumnType::Boolean,
-        "TEXT" => ColumnType::String,
-        s if s.starts_with("VARCHAR") => ColumnType::String,
-        "DATE" => ColumnType::DateTime,
-        x => panic!(format!(
-            "type {} is not supported here yet. Column was: {}",
-            x, column.name
-        )),
+    known_column_type(&column.tpe).unwrap_or_else(|| ColumnType {
+        raw: column.tpe.clone(),
+        family: ColumnTypeFamily::Unsupported(column.tpe.clone()),
+        precision: None,
+        scale: None,
+        character_maximum_length: None,
+    })
+}
+
+fn known_column_type(tpe: &str) -> Option<ColumnType> {
+    let (name, parameters) = split_declared_type(tpe);
+    // Multi-word type names like `DOUBLE PRECISION` may be spelled with any whitespace.
+    let name = name.split_whitespace().collect::<Vec<&str>>().join(" ").to_uppercase();
+    let family = match name.as_ref() {
+        "INTEGER" | "INT" | "TINYINT" | "SMALLINT" | "MEDIUMINT" => ColumnTypeFamily::Int,
+        "BIGINT" | "INT8" | "BIG INT" | "UNSIGNED BIG INT" => ColumnTypeFamily::BigInt,
+        "REAL" | "FLOAT" => ColumnTypeFamily::Float,
+        "DOUBLE" | "DOUBLE PRECISION" => ColumnTypeFamily::Double,
+        "NUMERIC" | "DECIMAL" => ColumnTypeFamily::Decimal,
+        "MONEY" => ColumnTypeFamily::Money,
+        "BOOLEAN" => ColumnTypeFamily::Boolean,
+        "TEXT" | "VARCHAR" => ColumnTypeFamily::String,
+        "DATE" | "DATETIME" | "TIMESTAMP" => ColumnTypeFamily::DateTime,
+        "BLOB" | "TINYBLOB" | "MEDIUMBLOB" | "LONGBLOB" | "BINARY" | "VARBINARY" | "BYTEA" => ColumnTypeFamily::Binary,
+        // Other names get the family of the affinity SQLite derives from them, e.g. `NVARCHAR(100)` is stored as
+        // text. See https://www.sqlite.org/datatype3.html#determination_of_column_affinity.
+        _ if name.contains("INT") => ColumnTypeFamily::Int,
+        _ if name.contains("CHAR") || name.contains("CLOB") || name.contains("TEXT") => ColumnTypeFamily::String,
+        _ => return None,
+    };
+    // The parameters of integer types are display widths.
+    let is_numeric = matches!(
+        family,
+        ColumnTypeFamily::Float | ColumnTypeFamily::Double | ColumnTypeFamily::Decimal
+    );
+    let is_character = family == ColumnTypeFamily::String || family == ColumnTypeFamily::Binary;
+
+    Some(ColumnType {
+        raw: tpe.to_string(),
+        family,
+        precision: parameters.first().cloned().filter(|_| is_numeric),
+        scale: parameters.get(1).cloned().filter(|_| is_numeric),
+        character_maximum_length: parameters.first().cloned().filter(|_| is_character),
+    })
+}
+
+/// Splits a declared type like `NUMERIC(10, 2)` into its name and its numeric parameters.
+fn split_declared_type(tpe: &str) -> (&str, Vec<u32>) {
+    match (tpe.find('('), tpe.rfind(')')) {
+        (Some(start), Some(end)) if start < end => (
+            tpe[..start].trim(),
+            tpe[start + 1..end]
+                .split(',')
+                .filter_map(|parameter| parameter.trim().parse().ok())
+                .collect(),
+        ),
+        _ => (tpe.trim(), Vec::new()),
+    }
+}
+
+/// Anything the pragma reports besides the actions SQLite knows is treated like its default, `NO ACTION`, instead
+/// of failing the whole introspection.
+fn foreign_key_action(action: &str) -> ForeignKeyAction {
+    match action {
+        "RESTRICT" => ForeignKeyAction::Restrict,
+        "CASCADE" => ForeignKeyAction::Cascade,
+        "SET NULL" => ForeignKeyAction::SetNull,
+        "SET DEFAULT" => ForeignKeyAction::SetDefault,
+        _ => ForeignKeyAction::NoAction,
     }
 }
 
@@ -161,13 +924,21 @@ struct IntrospectedColumn {
     tpe: String,
     default: Option<String>,
     is_required: bool,
+    /// The 1-based position of the column in the primary key, 0 if it is not part of it.
+    primary_key_position: i64,
+    /// The 0-based `cid` of the column.
+    position: i64,
 }
 
 #[derive(Debug)]
 struct IntrospectedForeignKey {
     name: String,
+    id: i64,
+    position: i64,
     table: String,
     column: String,
     referenced_table: String,
     referenced_column: String,
+    on_delete_action: String,
+    on_update_action: String,
 }
@@ -1,5 +1,6 @@
 mod database_inspector_impl;
 mod empty_impl;
+mod sqlite_ddl;
 
 pub use database_inspector_impl::*;
 pub use empty_impl::*;
@@ -11,6 +12,10 @@ pub trait DatabaseInspector {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct DatabaseSchema {
     pub tables: Vec<Table>,
+    pub views: Vec<View>,
+    pub enums: Vec<Enum>,
+    pub sequences: Vec<Sequence>,
+    pub virtual_tables: Vec<VirtualTable>,
 }
 
 impl DatabaseSchema {
@@ -21,16 +26,112 @@ impl DatabaseSchema {
     pub fn has_table(&self, name: &str) -> bool {
         self.table(name).is_some()
     }
+
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|v| v.name == name)
+    }
+
+    pub fn get_enum(&self, name: &str) -> Option<&Enum> {
+        self.enums.iter().find(|e| e.name == name)
+    }
+
+    /// The enum a column of `ColumnTypeFamily::Enum` refers to.
+    pub fn column_enum(&self, column: &Column) -> Option<&Enum> {
+        match &column.tpe.family {
+            ColumnTypeFamily::Enum(name) => self.get_enum(name),
+            _ => None,
+        }
+    }
+
+    pub fn get_virtual_table(&self, name: &str) -> Option<&VirtualTable> {
+        self.virtual_tables.iter().find(|v| v.name == name)
+    }
+
+    pub fn get_sequence(&self, name: &str) -> Option<&Sequence> {
+        self.sequences.iter().find(|s| s.name == name)
+    }
+
+    /// The sequence owned by a column, e.g. the one backing a Postgres `serial` or identity column.
+    pub fn sequence_for_column(&self, table: &str, column: &str) -> Option<&Sequence> {
+        self.sequences.iter().find(|s| match &s.owned_by {
+            Some(owner) => owner.table == table && owner.column == column,
+            None => false,
+        })
+    }
+}
+
+/// A SQLite virtual table, e.g. `CREATE VIRTUAL TABLE docs USING fts5(title, body)`. Its columns and storage are
+/// up to the module, so it is not reported as a table.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VirtualTable {
+    pub name: String,
+    /// The module implementing the table, e.g. `fts5` or `rtree`.
+    pub module: String,
+    /// The arguments passed to the module, as written.
+    pub arguments: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Enum {
+    pub name: String,
+    /// The values in the order they are declared in.
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct View {
+    pub name: String,
+    /// The defining query, if the database exposes it.
+    pub definition: Option<String>,
+    pub columns: Vec<ViewColumn>,
+    /// The triggers on the view. Views can only have INSTEAD OF triggers.
+    pub triggers: Vec<Trigger>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ViewColumn {
+    pub name: String,
+    /// The declared type of the projected column. Empty for computed columns on SQLite.
+    pub declared_type: String,
+    /// The type of the column, if the declared type maps to one.
+    pub tpe: Option<ColumnType>,
+    /// As reported by the database. View columns are often nullable even if the underlying column is not.
+    pub is_required: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Table {
     pub name: String,
+    /// Introspected columns are sorted by their `ordinal_position`.
     pub columns: Vec<Column>,
     pub indexes: Vec<Index>,
+    pub primary_key: Option<PrimaryKey>,
+    pub unique_constraints: Vec<UniqueConstraint>,
+    pub check_constraints: Vec<CheckConstraint>,
+    pub persistence: TablePersistence,
+    /// Whether a SQLite table was created `WITHOUT ROWID`, i.e. is stored in its primary key index and has no implicit
+    /// rowid primary key.
+    pub without_rowid: bool,
+    /// User defined triggers. Internal triggers, e.g. the ones Postgres uses to enforce foreign keys, are left out.
+    pub triggers: Vec<Trigger>,
 }
 
 impl Table {
+    /// A table without indexes or constraints, e.g. for tables that are not introspected from a database.
+    pub fn new(name: String, columns: Vec<Column>) -> Table {
+        Table {
+            name,
+            columns,
+            indexes: Vec::new(),
+            primary_key: None,
+            unique_constraints: Vec::new(),
+            check_constraints: Vec::new(),
+            persistence: TablePersistence::Permanent,
+            without_rowid: false,
+            triggers: Vec::new(),
+        }
+    }
+
     pub fn column(&self, name: &str) -> Option<&Column> {
         self.columns.iter().find(|c| c.name == name)
     }
@@ -40,13 +141,61 @@ impl Table {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Trigger {
+    pub name: String,
+    pub timing: TriggerTiming,
+    /// The events firing the trigger, e.g. `INSERT OR UPDATE`.
+    pub events: Vec<TriggerEvent>,
+    /// The expression of the `WHEN (...)` clause.
+    pub condition: Option<String>,
+    /// The statements the trigger executes.
+    pub body: String,
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, Clone)]
+pub enum TriggerTiming {
+    Before,
+    After,
+    InsteadOf,
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, Clone)]
+pub enum TriggerEvent {
+    Insert,
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, Clone)]
+pub enum TablePersistence {
+    Permanent,
+    /// Tables that only exist for the session that created them. Temporary tables of other sessions are never
+    /// introspected.
+    Temporary,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Column {
     pub name: String,
     pub tpe: ColumnType,
     pub is_required: bool,
     pub foreign_key: Option<ForeignKey>,
+    /// The sequence backing the column, e.g. the owned sequence of a Postgres `serial` primary key.
     pub sequence: Option<Sequence>,
+    /// Set for columns computed from other columns. Such columns cannot be written to.
+    pub generated: Option<GeneratedColumn>,
+    /// The collation of the column. None if the column uses the default collation.
+    pub collation: Option<String>,
+    /// The value the column takes when an insert does not provide one. None if there is no default clause.
+    pub default: Option<DefaultValue>,
+    /// The 1-based position of the column in the table, as in `information_schema.columns`. None for columns that
+    /// do not come from a database, e.g. the ones calculated from a data model.
+    pub ordinal_position: Option<u32>,
+    /// Whether the database assigns the values of the column on insert: Postgres `serial` and identity columns,
+    /// MySQL `AUTO_INCREMENT` columns and SQLite rowid aliases. A column that merely defaults to the `nextval` of a
+    /// sequence it does not own is not auto-incrementing.
+    pub auto_increment: bool,
 }
 
 impl Column {
@@ -57,6 +206,11 @@ impl Column {
             is_required,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: None,
+            auto_increment: false,
         }
     }
 
@@ -67,34 +221,204 @@ impl Column {
             is_required,
             foreign_key: Some(foreign_key),
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: None,
+            auto_increment: false,
         }
     }
 }
 
-#[derive(Debug, Copy, PartialEq, Eq, Clone)]
-pub enum ColumnType {
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ColumnType {
+    /// The type as declared in the database, e.g. `NUMERIC(10, 2)`.
+    pub raw: String,
+    pub family: ColumnTypeFamily,
+    /// The total number of digits of a numeric type, e.g. 10 for `NUMERIC(10, 2)`.
+    pub precision: Option<u32>,
+    /// The number of digits after the decimal point of a numeric type, e.g. 2 for `NUMERIC(10, 2)`.
+    pub scale: Option<u32>,
+    /// The maximum length of a character or binary string type, e.g. 255 for `VARCHAR(255)`. None for unbounded types
+    /// like `TEXT`.
+    pub character_maximum_length: Option<u32>,
+}
+
+impl ColumnType {
+    /// A type that only consists of a family, e.g. for columns that are not introspected from a database.
+    pub fn pure(family: ColumnTypeFamily) -> ColumnType {
+        ColumnType {
+            raw: "".to_string(),
+            family,
+            precision: None,
+            scale: None,
+            character_maximum_length: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ColumnTypeFamily {
+    /// Integers of up to 32 bits.
     Int,
+    /// 64-bit integers like `BIGINT`.
+    BigInt,
+    /// Single-precision floating point numbers like `REAL`.
     Float,
+    /// Double-precision floating point numbers like `DOUBLE PRECISION`.
+    Double,
+    /// Exact numeric types like `DECIMAL(10, 2)`.
+    Decimal,
     Boolean,
     String,
     DateTime,
+    /// Monetary types like the Postgres `money`. Kept apart from `Decimal`, as their precision depends on the locale.
+    Money,
+    /// Binary data like the Postgres `bytea`, MySQL `varbinary(255)` or SQLite `BLOB`.
+    Binary,
+    /// A column of an enum type, referencing the enum by name. See `DatabaseSchema::column_enum`.
+    Enum(String),
+    /// A type the inspector does not know, e.g. one provided by an extension. Carries the raw type.
+    Unsupported(String),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DefaultValue {
+    /// A constant, with the SQL quoting and casts removed, e.g. `draft` for `'draft'::text`.
+    Literal(String),
+    /// An explicit `DEFAULT NULL`. Postgres does not record such defaults at all, so there it is reported as no default.
+    Null,
+    /// A blob literal, e.g. `x'DEADBEEF'`.
+    Binary(Vec<u8>),
+    /// The default of a boolean column, whichever way the database spells it, e.g. `1`, `'t'` or `TRUE`.
+    Boolean(bool),
+    /// An expression evaluated on every insert, e.g. `abs(-1)`.
+    Expression(String),
+    /// The time of the insert, e.g. `CURRENT_TIMESTAMP` or `now()`, with the fractional seconds precision if given.
+    Now(Option<u32>),
+    /// A UUID generated by the database, e.g. with `gen_random_uuid()` or `uuid()`.
+    Uuid,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GeneratedColumn {
+    pub expression: String,
+    /// Whether the value is computed on write and stored (`STORED`), or computed on read (`VIRTUAL`).
+    pub stored: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ForeignKey {
     pub table: String,
     pub column: String,
+    pub constraint_name: Option<String>,
+    pub on_delete_action: ForeignKeyAction,
+    pub on_update_action: ForeignKeyAction,
+}
+
+impl ForeignKey {
+    /// Like `==`, but ignores the constraint name, which databases may generate on their own.
+    pub fn is_equivalent_to(&self, other: &ForeignKey) -> bool {
+        ForeignKey {
+            constraint_name: None,
+            ..self.clone()
+        } == ForeignKey {
+            constraint_name: None,
+            ..other.clone()
+        }
+    }
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, Clone)]
+pub enum ForeignKeyAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Sequence {
     pub name: String,
-    pub current: u32,
+    /// The value last handed out by the sequence. None if the sequence has not been used yet.
+    pub last_value: Option<i64>,
+    /// The column the sequence belongs to, i.e. `ALTER SEQUENCE ... OWNED BY`. None for standalone sequences.
+    pub owned_by: Option<SequenceOwner>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SequenceOwner {
+    pub table: String,
+    pub column: String,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Index {
     pub name: String,
-    pub columns: Vec<String>,
+    pub columns: Vec<IndexColumn>,
     pub unique: bool,
+    /// The `WHERE` clause of a partial index.
+    pub predicate: Option<String>,
+}
+
+impl Index {
+    /// The names of the plain columns of the index, skipping expressions.
+    pub fn column_names(&self) -> Vec<String> {
+        self.columns.iter().filter_map(|c| c.column_name()).collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IndexColumn {
+    pub value: IndexedValue,
+    pub sort_order: SortOrder,
+}
+
+impl IndexColumn {
+    pub fn column_name(&self) -> Option<String> {
+        match &self.value {
+            IndexedValue::Column(name) => Some(name.clone()),
+            IndexedValue::Expression(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum IndexedValue {
+    Column(String),
+    Expression(String),
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, Clone)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PrimaryKey {
+    pub columns: Vec<String>,
+    pub constraint_name: Option<String>,
+}
+
+impl PrimaryKey {
+    /// Compares the key columns only, ignoring the constraint name, which databases may generate on their own.
+    pub fn matches_columns(&self, other: &PrimaryKey) -> bool {
+        self.columns == other.columns
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UniqueConstraint {
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CheckConstraint {
+    pub name: Option<String>,
+    pub expression: String,
+    /// The columns the constraint is declared on or refers to, if known.
+    pub columns: Vec<String>,
 }
@@ -0,0 +1,508 @@
+//! SQLite keeps parts of the schema only as the original DDL text in `sqlite_master.sql`.
+//! This module tokenizes that text so the inspector can recover what the pragmas don't expose.
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TokenKind {
+    /// Keywords, unquoted identifiers and numbers.
+    Word(String),
+    /// Identifiers quoted with `"..."`, `` `...` `` or `[...]`, unescaped.
+    QuotedIdentifier(String),
+    /// String literals quoted with `'...'`, unescaped.
+    StringLiteral(String),
+    LeftParen,
+    RightParen,
+    Comma,
+    Symbol(char),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// Byte offsets of the token in the source text.
+    pub start: usize,
+    pub end: usize,
+    /// Parenthesis nesting level the token appears at.
+    pub depth: usize,
+}
+
+impl Token {
+    pub fn is_keyword(&self, keyword: &str) -> bool {
+        match &self.kind {
+            TokenKind::Word(word) => word.eq_ignore_ascii_case(keyword),
+            _ => false,
+        }
+    }
+}
+
+pub fn tokenize(sql: &str) -> Vec<Token> {
+    let chars: Vec<(usize, char)> = sql.char_indices().collect();
+    let offset = |i: usize| chars.get(i).map(|(offset, _)| *offset).unwrap_or(sql.len());
+    let mut tokens = Vec::new();
+    let mut depth = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        let next = chars.get(i + 1).map(|(_, c)| *c);
+
+        let (kind, next_i) = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '-' if next == Some('-') => {
+                while i < chars.len() && chars[i].1 != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i].1 == '*' && chars.get(i + 1).map(|(_, c)| *c) == Some('/')) {
+                    i += 1;
+                }
+                i += 2;
+                continue;
+            }
+            '\'' => {
+                let (value, next_i) = read_quoted(&chars, i, '\'');
+                (TokenKind::StringLiteral(value), next_i)
+            }
+            '"' | '`' => {
+                let (value, next_i) = read_quoted(&chars, i, c);
+                (TokenKind::QuotedIdentifier(value), next_i)
+            }
+            '[' => {
+                let (value, next_i) = read_quoted(&chars, i, ']');
+                (TokenKind::QuotedIdentifier(value), next_i)
+            }
+            '(' => (TokenKind::LeftParen, i + 1),
+            ')' => (TokenKind::RightParen, i + 1),
+            ',' => (TokenKind::Comma, i + 1),
+            c if is_word_char(c) => {
+                let mut end = i;
+                while end < chars.len() && is_word_char(chars[end].1) {
+                    end += 1;
+                }
+                let word = chars[i..end].iter().map(|(_, c)| c).collect();
+                (TokenKind::Word(word), end)
+            }
+            c => (TokenKind::Symbol(c), i + 1),
+        };
+
+        if kind == TokenKind::RightParen && depth > 0 {
+            depth -= 1;
+        }
+        tokens.push(Token {
+            kind: kind.clone(),
+            start,
+            end: offset(next_i),
+            depth,
+        });
+        if kind == TokenKind::LeftParen {
+            depth += 1;
+        }
+        i = next_i;
+    }
+
+    tokens
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CreateTable {
+    pub columns: Vec<ColumnDefinition>,
+    /// Table constraints, i.e. `PRIMARY KEY (...)`, `UNIQUE (...)`, `CHECK (...)` and `FOREIGN KEY (...)`.
+    pub constraints: Vec<Constraint>,
+    /// Whether the table was created `WITHOUT ROWID`.
+    pub without_rowid: bool,
+}
+
+impl CreateTable {
+    pub fn column(&self, name: &str) -> Option<&ColumnDefinition> {
+        self.columns.iter().find(|c| c.name.eq_ignore_ascii_case(name))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ColumnDefinition {
+    pub name: String,
+    /// The declared type, as written.
+    pub tpe: String,
+    pub constraints: Vec<Constraint>,
+}
+
+/// A column or table constraint, e.g. `CONSTRAINT positive CHECK (amount > 0)`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Constraint {
+    pub name: Option<String>,
+    /// The constraint without its `CONSTRAINT <name>` prefix.
+    pub source: String,
+    tokens: Vec<Token>,
+    /// Offset of the constraint in the statement, to resolve token positions against `source`.
+    offset: usize,
+}
+
+impl Constraint {
+    pub fn is(&self, keyword: &str) -> bool {
+        self.tokens.first().map(|t| t.is_keyword(keyword)).unwrap_or(false)
+    }
+
+    pub fn has_keyword(&self, keyword: &str) -> bool {
+        self.tokens.iter().any(|t| t.is_keyword(keyword))
+    }
+
+    pub fn ends_with(&self, keyword: &str) -> bool {
+        self.tokens.last().map(|t| t.is_keyword(keyword)).unwrap_or(false)
+    }
+
+    /// The text of the first parenthesized group of the constraint, e.g. the expression of a `CHECK`.
+    pub fn parenthesized(&self) -> Option<String> {
+        let start = self.tokens.iter().position(|t| t.kind == TokenKind::LeftParen)?;
+        let depth = self.tokens[start].depth;
+        let end = self.tokens[start + 1..]
+            .iter()
+            .position(|t| t.depth == depth && t.kind == TokenKind::RightParen)?
+            + start
+            + 1;
+
+        Some(self.text(&self.tokens[start + 1..end]).trim().to_string())
+    }
+
+    /// The column and the values of a `CHECK (<column> IN (<string literal>, ...))` constraint.
+    pub fn in_list(&self) -> Option<(String, Vec<String>)> {
+        let tokens = &self.tokens;
+        if !self.is("CHECK") || tokens.len() < 7 {
+            return None;
+        }
+        let column = match &tokens[2].kind {
+            TokenKind::Word(name) | TokenKind::QuotedIdentifier(name) => name.clone(),
+            _ => return None,
+        };
+        let is_in_list = tokens[1].kind == TokenKind::LeftParen
+            && tokens[3].is_keyword("IN")
+            && tokens[4].kind == TokenKind::LeftParen
+            && tokens[tokens.len() - 2].kind == TokenKind::RightParen
+            && tokens[tokens.len() - 1].kind == TokenKind::RightParen;
+        if !is_in_list {
+            return None;
+        }
+
+        let list = &tokens[5..tokens.len() - 2];
+        let mut values = Vec::new();
+        for (i, token) in list.iter().enumerate() {
+            match (&token.kind, i % 2) {
+                (TokenKind::StringLiteral(value), 0) => values.push(value.clone()),
+                (TokenKind::Comma, 1) => (),
+                _ => return None,
+            }
+        }
+
+        if list.len() % 2 == 1 {
+            Some((column, values))
+        } else {
+            None
+        }
+    }
+
+    /// All unquoted and quoted identifiers (and keywords) appearing in the constraint.
+    pub fn identifiers(&self) -> Vec<String> {
+        self.tokens
+            .iter()
+            .filter_map(|t| match &t.kind {
+                TokenKind::Word(name) | TokenKind::QuotedIdentifier(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The column names listed in the first parenthesized group, e.g. of `UNIQUE (a, b)`.
+    pub fn column_list(&self) -> Vec<String> {
+        match self.tokens.iter().position(|t| t.kind == TokenKind::LeftParen) {
+            Some(start) => split_list(&self.tokens[start + 1..])
+                .into_iter()
+                .filter_map(|item| item.first().and_then(identifier))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn text(&self, tokens: &[Token]) -> String {
+        match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => self.source[first.start - self.offset..last.end - self.offset].to_string(),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Parses a `CREATE TABLE` statement as stored in `sqlite_master`.
+pub fn parse_create_table(sql: &str) -> Option<CreateTable> {
+    let tokens = tokenize(sql);
+    let start = tokens
+        .iter()
+        .position(|t| t.depth == 0 && t.kind == TokenKind::LeftParen)?;
+    let end = tokens[start + 1..]
+        .iter()
+        .position(|t| t.depth == 0 && t.kind == TokenKind::RightParen)
+        .map(|end| end + start + 1)
+        .unwrap_or(tokens.len());
+    let mut result = CreateTable {
+        columns: Vec::new(),
+        constraints: Vec::new(),
+        // Table options follow the column list, e.g. `) WITHOUT ROWID, STRICT`.
+        without_rowid: tokens[end..]
+            .windows(2)
+            .any(|w| w[0].is_keyword("WITHOUT") && w[1].is_keyword("ROWID")),
+    };
+
+    for item in split_list(&tokens[start + 1..]) {
+        let is_table_constraint = ["CONSTRAINT", "PRIMARY", "UNIQUE", "CHECK", "FOREIGN"]
+            .iter()
+            .any(|keyword| item[0].is_keyword(keyword));
+
+        if is_table_constraint {
+            // A table constraint spans the whole item, only a `CONSTRAINT <name>` prefix is split off.
+            let (name, body) = if item[0].is_keyword("CONSTRAINT") && item.len() > 2 {
+                (identifier(&item[1]), &item[2..])
+            } else {
+                (None, item)
+            };
+            result.constraints.push(constraint(sql, name, body));
+        } else {
+            let clause_start = (1..item.len()).find(|i| starts_clause(item, *i)).unwrap_or(item.len());
+            result.columns.push(ColumnDefinition {
+                name: identifier(&item[0])?,
+                tpe: source(sql, &item[1..clause_start]),
+                constraints: constraints(sql, &item[clause_start..]),
+            });
+        }
+    }
+
+    Some(result)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CreateTrigger {
+    /// `BEFORE`, `AFTER` or `INSTEAD`, uppercased. None if omitted, which means `BEFORE`.
+    pub timing: Option<String>,
+    /// `DELETE`, `INSERT` or `UPDATE`, uppercased.
+    pub event: String,
+    /// The expression of the `WHEN` clause.
+    pub condition: Option<String>,
+    /// The statements between `BEGIN` and `END`.
+    pub body: String,
+}
+
+/// Parses `CREATE TRIGGER <name> [BEFORE | AFTER | INSTEAD OF] <event> ON <table> [FOR EACH ROW] [WHEN <expression>]
+/// BEGIN <statements> END`.
+pub fn parse_create_trigger(sql: &str) -> Option<CreateTrigger> {
+    let tokens = tokenize(sql);
+    let at_top_level = |keyword: &str, from: usize| {
+        tokens[from..]
+            .iter()
+            .position(|t| t.depth == 0 && t.is_keyword(keyword))
+            .map(|i| i + from)
+    };
+    let trigger = at_top_level("TRIGGER", 0)?;
+    let on = at_top_level("ON", trigger)?;
+    let begin = at_top_level("BEGIN", on)?;
+    let keyword = |keywords: &[&str]| {
+        tokens[trigger + 1..on].iter().find_map(|t| match &t.kind {
+            TokenKind::Word(word) if keywords.iter().any(|k| word.eq_ignore_ascii_case(k)) => Some(word.to_uppercase()),
+            _ => None,
+        })
+    };
+    let condition = at_top_level("WHEN", on)
+        .filter(|when| *when < begin)
+        .map(|when| source(sql, &tokens[when + 1..begin]));
+    let end = tokens.len() - 1;
+    if end <= begin || !tokens[end].is_keyword("END") {
+        return None;
+    }
+
+    Some(CreateTrigger {
+        timing: keyword(&["BEFORE", "AFTER", "INSTEAD"]),
+        event: keyword(&["DELETE", "INSERT", "UPDATE"])?,
+        condition,
+        body: source(sql, &tokens[begin + 1..end]),
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CreateVirtualTable {
+    pub module: String,
+    /// The module arguments, as written.
+    pub arguments: Vec<String>,
+}
+
+/// Parses `CREATE VIRTUAL TABLE <name> USING <module> [(<argument>, ...)]`. None for other statements.
+pub fn parse_create_virtual_table(sql: &str) -> Option<CreateVirtualTable> {
+    let tokens = tokenize(sql);
+    if !tokens.get(1)?.is_keyword("VIRTUAL") {
+        return None;
+    }
+    let using = tokens.iter().position(|t| t.depth == 0 && t.is_keyword("USING"))?;
+    let module = identifier(tokens.get(using + 1)?)?;
+    let arguments = match tokens.get(using + 2) {
+        Some(token) if token.kind == TokenKind::LeftParen => split_list(&tokens[using + 3..])
+            .into_iter()
+            .map(|argument| source(sql, argument))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Some(CreateVirtualTable { module, arguments })
+}
+
+/// Returns the `WHERE` clause of a `CREATE INDEX` statement.
+pub fn index_predicate(sql: &str) -> Option<String> {
+    tokenize(sql)
+        .iter()
+        .find(|t| t.depth == 0 && t.is_keyword("WHERE"))
+        .map(|t| sql[t.end..].trim().to_string())
+}
+
+/// Returns the indexed columns and expressions of a `CREATE INDEX` statement, without `COLLATE` and
+/// `ASC`/`DESC` suffixes.
+pub fn index_definitions(sql: &str) -> Vec<String> {
+    let tokens = tokenize(sql);
+    let column_list = tokens
+        .iter()
+        .position(|t| t.depth == 0 && t.is_keyword("ON"))
+        .and_then(|on| {
+            tokens[on..]
+                .iter()
+                .position(|t| t.kind == TokenKind::LeftParen)
+                .map(|i| on + i + 1)
+        });
+
+    match column_list {
+        Some(start) => split_list(&tokens[start..])
+            .into_iter()
+            .map(|item| {
+                let mut item = item;
+                if item.last().map(|t| t.is_keyword("ASC") || t.is_keyword("DESC")) == Some(true) {
+                    item = &item[..item.len() - 1];
+                }
+                if item.len() > 2 && item[item.len() - 2].is_keyword("COLLATE") {
+                    item = &item[..item.len() - 2];
+                }
+                source(sql, item)
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Splits the tokens following an opening parenthesis into its comma separated items, up to the
+/// matching closing parenthesis.
+pub fn split_list(tokens: &[Token]) -> Vec<&[Token]> {
+    let depth = match tokens.first() {
+        Some(token) => token.depth,
+        None => return Vec::new(),
+    };
+    let end = tokens.iter().position(|t| t.depth < depth).unwrap_or(tokens.len());
+
+    tokens[..end]
+        .split(|t| t.depth == depth && t.kind == TokenKind::Comma)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// The source text spanned by the given tokens.
+pub fn source(sql: &str, tokens: &[Token]) -> String {
+    match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => sql[first.start..last.end].to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Splits a sequence of constraints into individual constraints.
+fn constraints(sql: &str, tokens: &[Token]) -> Vec<Constraint> {
+    let mut starts: Vec<usize> = (0..tokens.len()).filter(|i| starts_clause(tokens, *i)).collect();
+    starts.push(tokens.len());
+    let mut result = Vec::new();
+    let mut name = None;
+
+    for window in starts.windows(2) {
+        let clause = &tokens[window[0]..window[1]];
+        if clause.is_empty() {
+            continue;
+        }
+        if clause[0].is_keyword("CONSTRAINT") {
+            name = clause.get(1).and_then(identifier);
+            continue;
+        }
+        result.push(constraint(sql, name.take(), clause));
+    }
+
+    result
+}
+
+fn constraint(sql: &str, name: Option<String>, tokens: &[Token]) -> Constraint {
+    Constraint {
+        name,
+        source: source(sql, tokens),
+        tokens: tokens.to_vec(),
+        offset: tokens[0].start,
+    }
+}
+
+/// Whether the token at `i` starts a new column or table constraint.
+fn starts_clause(tokens: &[Token], i: usize) -> bool {
+    let token = &tokens[i];
+    let depth = tokens[0].depth;
+    let previous = if i > 0 { tokens.get(i - 1) } else { None };
+    let follows = |keyword: &str| previous.map(|t| t.is_keyword(keyword)).unwrap_or(false);
+
+    if token.depth != depth {
+        return false;
+    }
+
+    match &token.kind {
+        TokenKind::Word(word) => match word.to_uppercase().as_str() {
+            "CONSTRAINT" | "PRIMARY" | "UNIQUE" | "CHECK" | "COLLATE" | "REFERENCES" | "GENERATED" | "FOREIGN" => true,
+            "DEFAULT" => !follows("SET"),
+            "NOT" => tokens.get(i + 1).map(|t| t.is_keyword("NULL")).unwrap_or(false),
+            "NULL" => !follows("NOT") && !follows("DEFAULT") && !follows("SET"),
+            "AS" => !follows("ALWAYS"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// The name an identifier token refers to. SQLite also accepts string literals as identifiers.
+fn identifier(token: &Token) -> Option<String> {
+    match &token.kind {
+        TokenKind::Word(name) | TokenKind::QuotedIdentifier(name) | TokenKind::StringLiteral(name) => {
+            Some(name.clone())
+        }
+        _ => None,
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Reads a quoted literal starting at `start`. A doubled closing quote is an escaped quote.
+fn read_quoted(chars: &[(usize, char)], start: usize, closing: char) -> (String, usize) {
+    let mut value = String::new();
+    let mut i = start + 1;
+
+    while i < chars.len() {
+        let c = chars[i].1;
+        if c == closing {
+            if chars.get(i + 1).map(|(_, c)| *c) == Some(closing) {
+                value.push(closing);
+                i += 2;
+                continue;
+            }
+            return (value, i + 1);
+        }
+        value.push(c);
+        i += 1;
+    }
+
+    (value, i)
+}
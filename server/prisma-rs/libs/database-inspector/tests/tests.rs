@@ -27,45 +27,111 @@ fn all_columns_types_must_work() {
     let expected_columns = vec![
         Column {
             name: "int".to_string(),
-            tpe: ColumnType::Int,
+            tpe: ColumnType {
+                raw: "INTEGER".to_string(),
+                family: ColumnTypeFamily::Int,
+                precision: None,
+                scale: None,
+                character_maximum_length: None,
+            },
             is_required: true,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: Some(1),
+            auto_increment: false,
         },
         Column {
             name: "float".to_string(),
-            tpe: ColumnType::Float,
+            tpe: ColumnType {
+                raw: "REAL".to_string(),
+                family: ColumnTypeFamily::Float,
+                precision: None,
+                scale: None,
+                character_maximum_length: None,
+            },
             is_required: true,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: Some(2),
+            auto_increment: false,
         },
         Column {
             name: "boolean".to_string(),
-            tpe: ColumnType::Boolean,
+            tpe: ColumnType {
+                raw: "BOOLEAN".to_string(),
+                family: ColumnTypeFamily::Boolean,
+                precision: None,
+                scale: None,
+                character_maximum_length: None,
+            },
             is_required: true,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: Some(3),
+            auto_increment: false,
         },
         Column {
             name: "string1".to_string(),
-            tpe: ColumnType::String,
+            tpe: ColumnType {
+                raw: "TEXT".to_string(),
+                family: ColumnTypeFamily::String,
+                precision: None,
+                scale: None,
+                character_maximum_length: None,
+            },
             is_required: true,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: Some(4),
+            auto_increment: false,
         },
         Column {
             name: "string2".to_string(),
-            tpe: ColumnType::String,
+            tpe: ColumnType {
+                raw: "VARCHAR(1)".to_string(),
+                family: ColumnTypeFamily::String,
+                precision: None,
+                scale: None,
+                character_maximum_length: Some(1),
+            },
             is_required: true,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: Some(5),
+            auto_increment: false,
         },
         Column {
             name: "date_time".to_string(),
-            tpe: ColumnType::DateTime,
+            tpe: ColumnType {
+                raw: "DATE".to_string(),
+                family: ColumnTypeFamily::DateTime,
+                precision: None,
+                scale: None,
+                character_maximum_length: None,
+            },
             is_required: true,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: Some(6),
+            auto_increment: false,
         },
     ];
 
@@ -87,17 +153,39 @@ fn is_required_must_work() {
     let expected_columns = vec![
         Column {
             name: "column1".to_string(),
-            tpe: ColumnType::Int,
+            tpe: ColumnType {
+                raw: "INTEGER".to_string(),
+                family: ColumnTypeFamily::Int,
+                precision: None,
+                scale: None,
+                character_maximum_length: None,
+            },
             is_required: true,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: Some(1),
+            auto_increment: false,
         },
         Column {
             name: "column2".to_string(),
-            tpe: ColumnType::Int,
+            tpe: ColumnType {
+                raw: "INTEGER".to_string(),
+                family: ColumnTypeFamily::Int,
+                precision: None,
+                scale: None,
+                character_maximum_length: None,
+            },
             is_required: false,
             foreign_key: None,
             sequence: None,
+            generated: None,
+            collation: None,
+            default: None,
+            ordinal_position: Some(2),
+            auto_increment: false,
         },
     ];
     assert_eq!(user_table.columns, expected_columns);
@@ -119,22 +207,1487 @@ fn foreign_keys_must_work() {
     let user_table = result.table("User").unwrap();
     let expected_columns = vec![Column {
         name: "city".to_string(),
-        tpe: ColumnType::Int,
+        tpe: ColumnType {
+            raw: "INTEGER".to_string(),
+            family: ColumnTypeFamily::Int,
+            precision: None,
+            scale: None,
+            character_maximum_length: None,
+        },
         is_required: true,
         foreign_key: Some(ForeignKey {
             table: "City".to_string(),
             column: "id".to_string(),
+            constraint_name: None,
+            on_delete_action: ForeignKeyAction::NoAction,
+            on_update_action: ForeignKeyAction::NoAction,
         }),
         sequence: None,
+        generated: None,
+        collation: None,
+        default: None,
+        ordinal_position: Some(1),
+        auto_increment: false,
     }];
     assert_eq!(user_table.columns, expected_columns);
 }
 
+#[test]
+fn foreign_key_equivalence_must_ignore_the_constraint_name() {
+    let foreign_key = ForeignKey {
+        table: "City".to_string(),
+        column: "id".to_string(),
+        constraint_name: Some("User_city_fkey".to_string()),
+        on_delete_action: ForeignKeyAction::NoAction,
+        on_update_action: ForeignKeyAction::NoAction,
+    };
+    let unnamed = ForeignKey {
+        constraint_name: None,
+        ..foreign_key.clone()
+    };
+    let cascading = ForeignKey {
+        on_delete_action: ForeignKeyAction::Cascade,
+        ..foreign_key.clone()
+    };
+
+    assert_ne!(foreign_key, unnamed);
+    assert!(foreign_key.is_equivalent_to(&unnamed));
+    assert!(!foreign_key.is_equivalent_to(&cascading));
+}
+
+#[test]
+fn foreign_key_constraint_names_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY);
+        CREATE TABLE "{0}"."City" (zip TEXT, country TEXT, PRIMARY KEY (zip, country));
+        CREATE TABLE "{0}"."Post" (
+            author INTEGER CONSTRAINT "Post_author_fkey" REFERENCES "User" (id),
+            editor INTEGER REFERENCES "User" (id),
+            city_zip TEXT,
+            city_country TEXT,
+            CONSTRAINT "Post_city_fkey" FOREIGN KEY (City_Zip, City_Country) REFERENCES "City" (zip, country)
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let post_table = result.table("Post").unwrap();
+    let constraint_name = |column: &str| {
+        post_table
+            .column(column)
+            .unwrap()
+            .foreign_key
+            .clone()
+            .unwrap()
+            .constraint_name
+    };
+    assert_eq!(constraint_name("author"), Some("Post_author_fkey".to_string()));
+    assert_eq!(constraint_name("editor"), None);
+    assert_eq!(constraint_name("city_zip"), Some("Post_city_fkey".to_string()));
+    assert_eq!(constraint_name("city_country"), Some("Post_city_fkey".to_string()));
+}
+
+#[test]
+fn foreign_key_actions_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."City" (id INTEGER PRIMARY KEY);
+        CREATE TABLE "{0}"."User" (
+            city_cascade INTEGER REFERENCES "City"(id) ON UPDATE CASCADE ON DELETE SET NULL,
+            city_restrict INTEGER REFERENCES "City"(id) ON UPDATE RESTRICT ON DELETE SET DEFAULT,
+            city_default INTEGER REFERENCES "City"(id)
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let user_table = result.table("User").unwrap();
+    let actions = |column: &str| {
+        let foreign_key = user_table.column(column).unwrap().foreign_key.clone().unwrap();
+        (foreign_key.on_update_action, foreign_key.on_delete_action)
+    };
+    assert_eq!(
+        actions("city_cascade"),
+        (ForeignKeyAction::Cascade, ForeignKeyAction::SetNull)
+    );
+    assert_eq!(
+        actions("city_restrict"),
+        (ForeignKeyAction::Restrict, ForeignKeyAction::SetDefault)
+    );
+    assert_eq!(
+        actions("city_default"),
+        (ForeignKeyAction::NoAction, ForeignKeyAction::NoAction)
+    );
+}
+
+#[test]
+fn composite_foreign_keys_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."City" (zip TEXT, country TEXT, name TEXT, PRIMARY KEY (zip, country, name));
+        CREATE TABLE "{0}"."User" (
+            c_name TEXT,
+            a_zip TEXT,
+            b_country TEXT,
+            FOREIGN KEY (a_zip, c_name, b_country) REFERENCES "City"(zip, name, country)
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let user_table = result.table("User").unwrap();
+    let referenced_column = |column: &str| user_table.column(column).unwrap().foreign_key.clone().unwrap().column;
+    assert_eq!(referenced_column("a_zip"), "zip");
+    assert_eq!(referenced_column("c_name"), "name");
+    assert_eq!(referenced_column("b_country"), "country");
+}
+
+#[test]
+fn foreign_keys_without_a_column_list_must_reference_the_primary_key() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY, name TEXT);
+        CREATE TABLE "{0}"."City" (zip TEXT, country TEXT, PRIMARY KEY (country, zip));
+        CREATE TABLE "{0}"."Post" (
+            author INTEGER REFERENCES "User",
+            city_country TEXT,
+            city_zip TEXT,
+            FOREIGN KEY (city_country, city_zip) REFERENCES "City"
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let post_table = result.table("Post").unwrap();
+    let referenced_column = |column: &str| post_table.column(column).unwrap().foreign_key.clone().unwrap().column;
+    assert_eq!(referenced_column("author"), "id");
+    assert_eq!(referenced_column("city_country"), "country");
+    assert_eq!(referenced_column("city_zip"), "zip");
+}
+
+#[test]
+fn self_referencing_foreign_keys_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Employee" (
+            id INTEGER PRIMARY KEY,
+            manager_id INTEGER REFERENCES "Employee"(id) ON DELETE SET NULL,
+            mentor_id INTEGER REFERENCES "Employee"(id) ON DELETE CASCADE
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let employee_table = result.table("Employee").unwrap();
+    let foreign_key = |column: &str| employee_table.column(column).unwrap().foreign_key.clone().unwrap();
+    assert_eq!(employee_table.column("id").unwrap().foreign_key, None);
+    assert_eq!(foreign_key("manager_id").table, "Employee");
+    assert_eq!(foreign_key("manager_id").on_delete_action, ForeignKeyAction::SetNull);
+    assert_eq!(foreign_key("mentor_id").table, "Employee");
+    assert_eq!(foreign_key("mentor_id").on_delete_action, ForeignKeyAction::Cascade);
+}
+
+#[test]
+fn index_sort_order_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY, name TEXT, age INTEGER);
+        CREATE UNIQUE INDEX "{0}"."User_name_age" ON "User" (name ASC, age DESC);
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let user_table = result.table("User").unwrap();
+    let expected_indexes = vec![Index {
+        name: "User_name_age".to_string(),
+        columns: vec![
+            IndexColumn {
+                value: IndexedValue::Column("name".to_string()),
+                sort_order: SortOrder::Asc,
+            },
+            IndexColumn {
+                value: IndexedValue::Column("age".to_string()),
+                sort_order: SortOrder::Desc,
+            },
+        ],
+        unique: true,
+        predicate: None,
+    }];
+    assert_eq!(user_table.indexes, expected_indexes);
+}
+
+#[test]
+fn partial_indexes_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY, email TEXT, deleted_at DATE);
+        CREATE UNIQUE INDEX "{0}"."User_email" ON "User" (email) WHERE deleted_at IS NULL AND email != 'a where b';
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let index = &result.table("User").unwrap().indexes[0];
+    assert_eq!(index.column_names(), vec!["email".to_string()]);
+    assert!(index.unique);
+    assert_eq!(
+        index.predicate,
+        Some("deleted_at IS NULL AND email != 'a where b'".to_string())
+    );
+}
+
+#[test]
+fn expression_indexes_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY, email TEXT, name TEXT);
+        CREATE INDEX "{0}"."User_email" ON "User" (lower(email) COLLATE NOCASE DESC, name, substr(name, 1, 2));
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let index = &result.table("User").unwrap().indexes[0];
+    let expected_columns = vec![
+        IndexColumn {
+            value: IndexedValue::Expression("lower(email)".to_string()),
+            sort_order: SortOrder::Desc,
+        },
+        IndexColumn {
+            value: IndexedValue::Column("name".to_string()),
+            sort_order: SortOrder::Asc,
+        },
+        IndexColumn {
+            value: IndexedValue::Expression("substr(name, 1, 2)".to_string()),
+            sort_order: SortOrder::Asc,
+        },
+    ];
+    assert_eq!(index.columns, expected_columns);
+    assert_eq!(index.column_names(), vec!["name".to_string()]);
+}
+
+#[test]
+fn names_with_quotes_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Us""er" (id INTEGER PRIMARY KEY);
+        CREATE TABLE "{0}"."Po""st" (id INTEGER PRIMARY KEY, author INTEGER REFERENCES "Us""er" (id));
+        CREATE INDEX "{0}"."i""dx" ON "Po""st" (author DESC);
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let post_table = result.table(r#"Po"st"#).unwrap();
+    let author = post_table.column("author").unwrap();
+    assert_eq!(
+        author.foreign_key.as_ref().map(|fk| fk.table.as_str()),
+        Some(r#"Us"er"#)
+    );
+    let expected_indexes = vec![Index {
+        name: r#"i"dx"#.to_string(),
+        columns: vec![IndexColumn {
+            value: IndexedValue::Column("author".to_string()),
+            sort_order: SortOrder::Desc,
+        }],
+        unique: false,
+        predicate: None,
+    }];
+    assert_eq!(post_table.indexes, expected_indexes);
+}
+
+#[test]
+fn constraint_indexes_must_not_be_reported_as_indexes() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (
+            country TEXT,
+            name TEXT,
+            email TEXT UNIQUE,
+            age INTEGER,
+            PRIMARY KEY (name, country)
+        );
+        CREATE INDEX "{0}"."User_age" ON "User" (age);
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let user_table = result.table("User").unwrap();
+    let index_names: Vec<&str> = user_table.indexes.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(index_names, vec!["User_age"]);
+    assert_eq!(
+        user_table.primary_key,
+        Some(PrimaryKey {
+            columns: vec!["name".to_string(), "country".to_string()],
+            constraint_name: None,
+        })
+    );
+    assert_eq!(
+        user_table.unique_constraints,
+        vec![UniqueConstraint {
+            name: None,
+            columns: vec!["email".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn unique_constraints_must_be_distinguishable_from_unique_indexes() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (
+            email TEXT CONSTRAINT "User_email_unique" UNIQUE NOT NULL,
+            first_name TEXT,
+            last_name TEXT,
+            nickname TEXT,
+            CONSTRAINT "User_name_unique" UNIQUE (first_name, last_name)
+        );
+        CREATE UNIQUE INDEX "{0}"."User_nickname" ON "User" (nickname);
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let user_table = result.table("User").unwrap();
+    let index_names: Vec<&str> = user_table.indexes.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(index_names, vec!["User_nickname"]);
+    assert!(user_table.indexes[0].unique);
+
+    let mut unique_constraints = user_table.unique_constraints.clone();
+    unique_constraints.sort_by_key(|c| c.name.clone());
+    assert_eq!(
+        unique_constraints,
+        vec![
+            UniqueConstraint {
+                name: Some("User_email_unique".to_string()),
+                columns: vec!["email".to_string()],
+            },
+            UniqueConstraint {
+                name: Some("User_name_unique".to_string()),
+                columns: vec!["first_name".to_string(), "last_name".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn unique_constraint_names_must_ignore_the_case_of_the_columns() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (
+            first_name TEXT,
+            last_name TEXT,
+            email TEXT,
+            CONSTRAINT "User_name_unique" UNIQUE (First_Name, LAST_NAME),
+            CONSTRAINT "User_email_unique" UNIQUE (EMAIL)
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let mut unique_constraints = result.table("User").unwrap().unique_constraints.clone();
+    unique_constraints.sort_by_key(|c| c.name.clone());
+    assert_eq!(
+        unique_constraints,
+        vec![
+            UniqueConstraint {
+                name: Some("User_email_unique".to_string()),
+                columns: vec!["email".to_string()],
+            },
+            UniqueConstraint {
+                name: Some("User_name_unique".to_string()),
+                columns: vec!["first_name".to_string(), "last_name".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn rowid_primary_keys_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY, name TEXT);"#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let user_table = result.table("User").unwrap();
+    assert_eq!(
+        user_table.primary_key,
+        Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            constraint_name: None,
+        })
+    );
+    assert_eq!(user_table.indexes, vec![]);
+}
+
+#[test]
+fn primary_key_constraint_names_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (id INTEGER CONSTRAINT "User_pkey" PRIMARY KEY, name TEXT);
+        CREATE TABLE "{0}"."Post" (
+            author TEXT,
+            title TEXT,
+            CONSTRAINT "Post_pkey" PRIMARY KEY (author, title)
+        );
+        CREATE TABLE "{0}"."Tag" (id INTEGER, CONSTRAINT "Tag_pkey" PRIMARY KEY (ID));
+        CREATE TABLE "{0}"."Vote" (
+            post_author TEXT,
+            post_title TEXT,
+            CONSTRAINT "Vote_pkey" PRIMARY KEY (Post_Author, POST_TITLE)
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let user_primary_key = result.table("User").unwrap().primary_key.clone().unwrap();
+    assert_eq!(user_primary_key.constraint_name, Some("User_pkey".to_string()));
+
+    let post_primary_key = result.table("Post").unwrap().primary_key.clone().unwrap();
+    assert_eq!(post_primary_key.constraint_name, Some("Post_pkey".to_string()));
+    assert!(post_primary_key.matches_columns(&PrimaryKey {
+        columns: vec!["author".to_string(), "title".to_string()],
+        constraint_name: None,
+    }));
+
+    // Identifiers are case insensitive, the constraint may spell its columns differently than they are declared.
+    let tag_primary_key = result.table("Tag").unwrap().primary_key.clone().unwrap();
+    assert_eq!(tag_primary_key.columns, vec!["id".to_string()]);
+    assert_eq!(tag_primary_key.constraint_name, Some("Tag_pkey".to_string()));
+
+    let vote_primary_key = result.table("Vote").unwrap().primary_key.clone().unwrap();
+    assert_eq!(vote_primary_key.constraint_name, Some("Vote_pkey".to_string()));
+}
+
+#[test]
+fn check_constraints_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Task" (
+            name TEXT CHECK(length(name) > 0),
+            status TEXT,
+            [due date] DATE,
+            "created at" DATE,
+            note TEXT DEFAULT 'a, (b' CONSTRAINT "Task_note_check" CHECK (note != 'it''s, (odd'),
+            CHECK (status IN ('new','done')),
+            CONSTRAINT `Task_dates_check` CHECK ([due date] > "created at")
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let task_table = result.table("Task").unwrap();
+    let expected_check_constraints = vec![
+        CheckConstraint {
+            name: None,
+            expression: "length(name) > 0".to_string(),
+            columns: vec!["name".to_string()],
+        },
+        CheckConstraint {
+            name: Some("Task_note_check".to_string()),
+            expression: "note != 'it''s, (odd'".to_string(),
+            columns: vec!["note".to_string()],
+        },
+        CheckConstraint {
+            name: None,
+            expression: "status IN ('new','done')".to_string(),
+            columns: vec!["status".to_string()],
+        },
+        CheckConstraint {
+            name: Some("Task_dates_check".to_string()),
+            expression: r#"[due date] > "created at""#.to_string(),
+            columns: vec!["due date".to_string(), "created at".to_string()],
+        },
+    ];
+    assert_eq!(task_table.check_constraints, expected_check_constraints);
+}
+
+#[test]
+fn views_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Item" (a INTEGER);
+        CREATE VIEW "{0}"."ItemView" AS SELECT a, a + 1 AS b FROM "Item";
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    assert!(result.has_table("Item"));
+    assert!(!result.has_table("ItemView"));
+    let view = result.get_view("ItemView").unwrap();
+    assert_eq!(
+        view.definition,
+        Some(r#"CREATE VIEW "ItemView" AS SELECT a, a + 1 AS b FROM "Item""#.to_string())
+    );
+    assert_eq!(
+        view.columns,
+        vec![
+            ViewColumn {
+                name: "a".to_string(),
+                declared_type: "INTEGER".to_string(),
+                tpe: Some(ColumnType {
+                    raw: "INTEGER".to_string(),
+                    family: ColumnTypeFamily::Int,
+                    precision: None,
+                    scale: None,
+                    character_maximum_length: None,
+                }),
+                is_required: false,
+            },
+            ViewColumn {
+                name: "b".to_string(),
+                declared_type: "".to_string(),
+                tpe: None,
+                is_required: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn view_columns_must_report_type_and_nullability() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (id INTEGER NOT NULL, name VARCHAR(255));
+        CREATE VIEW "{0}"."UserView" AS SELECT id, name FROM "User";
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let view = result.get_view("UserView").unwrap();
+    assert_eq!(
+        view.columns,
+        vec![
+            ViewColumn {
+                name: "id".to_string(),
+                declared_type: "INTEGER".to_string(),
+                tpe: Some(ColumnType {
+                    raw: "INTEGER".to_string(),
+                    family: ColumnTypeFamily::Int,
+                    precision: None,
+                    scale: None,
+                    character_maximum_length: None,
+                }),
+                // SQLite reports every view column as nullable.
+                is_required: false,
+            },
+            ViewColumn {
+                name: "name".to_string(),
+                declared_type: "VARCHAR(255)".to_string(),
+                tpe: Some(ColumnType {
+                    raw: "VARCHAR(255)".to_string(),
+                    family: ColumnTypeFamily::String,
+                    precision: None,
+                    scale: None,
+                    character_maximum_length: Some(255),
+                }),
+                is_required: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn views_with_a_dropped_base_table_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Item" (a INTEGER);
+        CREATE VIEW "{0}"."ItemView" AS SELECT a FROM "Item";
+        DROP TABLE "{0}"."Item";
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let view = result.get_view("ItemView").unwrap();
+    assert_eq!(
+        view.definition,
+        Some(r#"CREATE VIEW "ItemView" AS SELECT a FROM "Item""#.to_string())
+    );
+    assert_eq!(view.columns, vec![]);
+}
+
+#[test]
+fn generated_columns_must_work() {
+    // Generated columns were added in SQLite 3.31.
+    if rusqlite::version_number() < 3031000 {
+        return;
+    }
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."OrderLine" (
+            price REAL NOT NULL,
+            quantity INTEGER NOT NULL,
+            total REAL GENERATED ALWAYS AS (price * (quantity + 0)) VIRTUAL,
+            label TEXT AS ('x' || ')' || quantity) STORED
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("OrderLine").unwrap();
+    assert_eq!(table.column("price").unwrap().generated, None);
+    assert_eq!(
+        table.column("total").unwrap().generated,
+        Some(GeneratedColumn {
+            expression: "price * (quantity + 0)".to_string(),
+            stored: false,
+        })
+    );
+    assert_eq!(
+        table.column("label").unwrap().generated,
+        Some(GeneratedColumn {
+            expression: "'x' || ')' || quantity".to_string(),
+            stored: true,
+        })
+    );
+}
+
+#[test]
+fn column_collations_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (
+            email TEXT COLLATE NOCASE UNIQUE,
+            name TEXT NOT NULL COLLATE "RTRIM",
+            bio TEXT,
+            code TEXT COLLATE BINARY
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("User").unwrap();
+    assert_eq!(table.column("email").unwrap().collation, Some("NOCASE".to_string()));
+    assert_eq!(table.column("name").unwrap().collation, Some("RTRIM".to_string()));
+    assert_eq!(table.column("bio").unwrap().collation, None);
+    assert_eq!(table.column("code").unwrap().collation, None);
+}
+
+#[test]
+fn numeric_precision_and_scale_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Price" (
+            amount NUMERIC(10,2),
+            rate DECIMAL( 20 , 8 ),
+            rounded DECIMAL(5),
+            ratio REAL,
+            code VARCHAR(10),
+            balance MONEY,
+            single FLOAT,
+            double DOUBLE,
+            double_precision DOUBLE  PRECISION
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Price").unwrap();
+    let precision_and_scale = |name: &str| {
+        let tpe = &table.column(name).unwrap().tpe;
+        (tpe.family.clone(), tpe.precision, tpe.scale)
+    };
+    assert_eq!(
+        precision_and_scale("amount"),
+        (ColumnTypeFamily::Decimal, Some(10), Some(2))
+    );
+    assert_eq!(
+        precision_and_scale("rate"),
+        (ColumnTypeFamily::Decimal, Some(20), Some(8))
+    );
+    assert_eq!(
+        precision_and_scale("rounded"),
+        (ColumnTypeFamily::Decimal, Some(5), None)
+    );
+    assert_eq!(precision_and_scale("ratio"), (ColumnTypeFamily::Float, None, None));
+    assert_eq!(precision_and_scale("code"), (ColumnTypeFamily::String, None, None));
+    assert_eq!(precision_and_scale("balance"), (ColumnTypeFamily::Money, None, None));
+    assert_eq!(precision_and_scale("single"), (ColumnTypeFamily::Float, None, None));
+    assert_eq!(precision_and_scale("double"), (ColumnTypeFamily::Double, None, None));
+    assert_eq!(
+        precision_and_scale("double_precision"),
+        (ColumnTypeFamily::Double, None, None)
+    );
+    assert_eq!(table.column("rate").unwrap().tpe.raw, "DECIMAL( 20 , 8 )");
+}
+
+#[test]
+fn character_maximum_length_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."User" (
+            email VARCHAR(191),
+            name VARCHAR(255),
+            bio TEXT,
+            age INTEGER
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("User").unwrap();
+    let length = |name: &str| table.column(name).unwrap().tpe.character_maximum_length;
+    assert_eq!(length("email"), Some(191));
+    assert_eq!(length("name"), Some(255));
+    assert_eq!(length("bio"), None);
+    assert_eq!(length("age"), None);
+}
+
+#[test]
+fn check_constraint_enums_must_work() {
+    let sql = format!(
+        r#"
+        CREATE TABLE "{0}"."Task" (
+            status TEXT CHECK (status IN ('new', 'in progress', 'done')),
+            label TEXT CHECK ("label" IN ('it''s', 'a, b')),
+            priority TEXT CHECK (priority IN ('low', 'high') OR priority IS NULL),
+            kind TEXT,
+            CHECK (kind IN ('bug', 'feature'))
+        );
+        "#,
+        SCHEMA
+    );
+    let options = IntrospectionOptions {
+        enums_from_check_constraints: true,
+        ..IntrospectionOptions::default()
+    };
+
+    let result = setup_sql_with_options(&sql, options).introspect(&SCHEMA.to_string());
+
+    assert_eq!(
+        result.enums,
+        vec![
+            Enum {
+                name: "Task_status".to_string(),
+                values: vec!["new".to_string(), "in progress".to_string(), "done".to_string()],
+            },
+            Enum {
+                name: "Task_label".to_string(),
+                values: vec!["it's".to_string(), "a, b".to_string()],
+            },
+            Enum {
+                name: "Task_kind".to_string(),
+                values: vec!["bug".to_string(), "feature".to_string()],
+            },
+        ]
+    );
+    let task_table = result.table("Task").unwrap();
+    assert_eq!(task_table.check_constraints.len(), 4);
+    let status_column = task_table.column("status").unwrap();
+    assert_eq!(
+        status_column.tpe.family,
+        ColumnTypeFamily::Enum("Task_status".to_string())
+    );
+    assert_eq!(status_column.tpe.raw, "TEXT");
+    assert_eq!(result.column_enum(status_column), result.get_enum("Task_status"));
+    let priority_column = task_table.column("priority").unwrap();
+    assert_eq!(priority_column.tpe.family, ColumnTypeFamily::String);
+    assert_eq!(result.column_enum(priority_column), None);
+
+    let result = setup_sql(&sql).introspect(&SCHEMA.to_string());
+
+    assert!(result.enums.is_empty());
+    let status_column = result.table("Task").unwrap().column("status").unwrap();
+    assert_eq!(status_column.tpe.family, ColumnTypeFamily::String);
+}
+
+#[test]
+fn unsupported_column_types_must_not_break_introspection() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Place" (
+            id INTEGER,
+            location GEOMETRY,
+            anything
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Place").unwrap();
+    assert_eq!(table.column("id").unwrap().tpe.family, ColumnTypeFamily::Int);
+    let location_type = &table.column("location").unwrap().tpe;
+    assert_eq!(
+        location_type.family,
+        ColumnTypeFamily::Unsupported("GEOMETRY".to_string())
+    );
+    assert_eq!(location_type.raw, "GEOMETRY");
+    assert_eq!(
+        table.column("anything").unwrap().tpe.family,
+        ColumnTypeFamily::Unsupported("".to_string())
+    );
+}
+
+#[test]
+fn column_types_must_be_mapped_by_affinity() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Record" (
+            a DATETIME,
+            b TIMESTAMP,
+            c CHAR(10),
+            d NVARCHAR(100),
+            e CLOB,
+            f UNSIGNED BIG INT,
+            g UNSIGNED INTEGER,
+            h VARYING CHARACTER(255)
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Record").unwrap();
+    let tpe = |name: &str| table.column(name).unwrap().tpe.clone();
+    assert_eq!(tpe("a").family, ColumnTypeFamily::DateTime);
+    assert_eq!(tpe("b").family, ColumnTypeFamily::DateTime);
+    assert_eq!(tpe("c").family, ColumnTypeFamily::String);
+    assert_eq!(tpe("c").character_maximum_length, Some(10));
+    assert_eq!(tpe("d").family, ColumnTypeFamily::String);
+    assert_eq!(tpe("d").character_maximum_length, Some(100));
+    assert_eq!(tpe("e").family, ColumnTypeFamily::String);
+    assert_eq!(tpe("f").family, ColumnTypeFamily::BigInt);
+    assert_eq!(tpe("g").family, ColumnTypeFamily::Int);
+    assert_eq!(tpe("h").family, ColumnTypeFamily::String);
+    assert_eq!(tpe("h").character_maximum_length, Some(255));
+}
+
+#[test]
+fn binary_columns_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."File" (
+            a BLOB,
+            b blob,
+            c TINYBLOB,
+            d MEDIUMBLOB,
+            e LONGBLOB,
+            f BINARY(16),
+            g VARBINARY(255),
+            h BYTEA
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("File").unwrap();
+    for column in &table.columns {
+        assert_eq!(column.tpe.family, ColumnTypeFamily::Binary, "{}", column.name);
+    }
+    assert_eq!(table.column("a").unwrap().tpe.character_maximum_length, None);
+    assert_eq!(table.column("f").unwrap().tpe.character_maximum_length, Some(16));
+    assert_eq!(table.column("g").unwrap().tpe.character_maximum_length, Some(255));
+}
+
+#[test]
+fn integer_widths_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Counter" (
+            a INTEGER,
+            b INT,
+            c TINYINT,
+            d SMALLINT,
+            e MEDIUMINT,
+            f BIGINT,
+            g INT8
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Counter").unwrap();
+    let family = |name: &str| table.column(name).unwrap().tpe.family.clone();
+    assert_eq!(family("a"), ColumnTypeFamily::Int);
+    assert_eq!(family("b"), ColumnTypeFamily::Int);
+    assert_eq!(family("c"), ColumnTypeFamily::Int);
+    assert_eq!(family("d"), ColumnTypeFamily::Int);
+    assert_eq!(family("e"), ColumnTypeFamily::Int);
+    assert_eq!(family("f"), ColumnTypeFamily::BigInt);
+    assert_eq!(family("g"), ColumnTypeFamily::BigInt);
+}
+
+#[test]
+fn column_defaults_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Post" (
+            status TEXT DEFAULT 'draft',
+            views INTEGER DEFAULT 0,
+            rating REAL DEFAULT -1.5,
+            published DATE DEFAULT CURRENT_TIMESTAMP,
+            score INTEGER DEFAULT (abs(-1)),
+            title TEXT
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Post").unwrap();
+    let default = |name: &str| table.column(name).unwrap().default.clone();
+    assert_eq!(default("status"), Some(DefaultValue::Literal("draft".to_string())));
+    assert_eq!(default("views"), Some(DefaultValue::Literal("0".to_string())));
+    assert_eq!(default("rating"), Some(DefaultValue::Literal("-1.5".to_string())));
+    assert_eq!(default("published"), Some(DefaultValue::Now(None)));
+    assert_eq!(default("score"), Some(DefaultValue::Expression("abs(-1)".to_string())));
+    assert_eq!(default("title"), None);
+}
+
+#[test]
+fn boolean_defaults_must_be_normalized() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Flags" (
+            a BOOLEAN DEFAULT 1,
+            b BOOLEAN DEFAULT 0,
+            c BOOLEAN DEFAULT 't',
+            d BOOLEAN DEFAULT 'f',
+            e BOOLEAN DEFAULT TRUE,
+            f BOOLEAN DEFAULT false,
+            g INTEGER DEFAULT 1
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Flags").unwrap();
+    let default = |name: &str| table.column(name).unwrap().default.clone();
+    assert_eq!(default("a"), Some(DefaultValue::Boolean(true)));
+    assert_eq!(default("b"), Some(DefaultValue::Boolean(false)));
+    assert_eq!(default("c"), Some(DefaultValue::Boolean(true)));
+    assert_eq!(default("d"), Some(DefaultValue::Boolean(false)));
+    assert_eq!(default("e"), Some(DefaultValue::Boolean(true)));
+    assert_eq!(default("f"), Some(DefaultValue::Boolean(false)));
+    // Only boolean columns are normalized.
+    assert_eq!(default("g"), Some(DefaultValue::Literal("1".to_string())));
+}
+
+#[test]
+fn now_defaults_must_be_normalized() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Event" (
+            a DATE DEFAULT CURRENT_TIMESTAMP,
+            b DATE DEFAULT current_timestamp,
+            c DATE DEFAULT (CURRENT_TIMESTAMP),
+            d DATE DEFAULT (datetime('now')),
+            e DATE DEFAULT ( DateTime( 'now' ) ),
+            f DATE DEFAULT (NOW()),
+            g DATE DEFAULT (now(6)),
+            h DATE DEFAULT (now( 3 )),
+            i DATE DEFAULT (datetime('now', 'localtime')),
+            j DATE DEFAULT CURRENT_DATE,
+            k DATE DEFAULT current_time,
+            l DATE DEFAULT (CURRENT_DATE)
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Event").unwrap();
+    let default = |name: &str| table.column(name).unwrap().default.clone();
+    for name in &["a", "b", "c", "d", "e", "f"] {
+        assert_eq!(default(name), Some(DefaultValue::Now(None)), "column {}", name);
+    }
+    assert_eq!(default("g"), Some(DefaultValue::Now(Some(6))));
+    assert_eq!(default("h"), Some(DefaultValue::Now(Some(3))));
+    assert_eq!(
+        default("i"),
+        Some(DefaultValue::Expression("datetime('now', 'localtime')".to_string()))
+    );
+    // Only the date or the time of day, not the time of the insert.
+    assert_eq!(default("j"), Some(DefaultValue::Expression("CURRENT_DATE".to_string())));
+    assert_eq!(default("k"), Some(DefaultValue::Expression("current_time".to_string())));
+    assert_eq!(default("l"), Some(DefaultValue::Expression("CURRENT_DATE".to_string())));
+}
+
+#[test]
+fn uuid_defaults_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Account" (
+            id TEXT PRIMARY KEY DEFAULT (uuid()),
+            a TEXT DEFAULT (gen_random_uuid()),
+            b TEXT DEFAULT (UUID_GENERATE_V4( )),
+            c TEXT DEFAULT (lower(hex(randomblob(16))))
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Account").unwrap();
+    let default = |name: &str| table.column(name).unwrap().default.clone();
+    assert_eq!(default("id"), Some(DefaultValue::Uuid));
+    assert_eq!(default("a"), Some(DefaultValue::Uuid));
+    assert_eq!(default("b"), Some(DefaultValue::Uuid));
+    assert_eq!(
+        default("c"),
+        Some(DefaultValue::Expression("lower(hex(randomblob(16)))".to_string()))
+    );
+}
+
+#[test]
+fn quoted_and_tricky_defaults_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Tricky" (
+            a TEXT DEFAULT 'it''s',
+            b TEXT DEFAULT "",
+            c TEXT DEFAULT '',
+            d TEXT DEFAULT 'say "hi"',
+            e TEXT DEFAULT "a ""quoted"" word",
+            f TEXT DEFAULT 'a::b',
+            g TEXT DEFAULT ' ( padded ) ',
+            h BLOB DEFAULT x'DEADBEEF',
+            i BLOB DEFAULT X'',
+            j INTEGER DEFAULT (abs(-1)),
+            k TEXT DEFAULT ('a' || 'b'),
+            l INTEGER DEFAULT +5,
+            m INTEGER DEFAULT 0x1F,
+            n TEXT DEFAULT NULL,
+            o TEXT DEFAULT null
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Tricky").unwrap();
+    let default = |name: &str| table.column(name).unwrap().default.clone();
+    let literal = |value: &str| Some(DefaultValue::Literal(value.to_string()));
+    assert_eq!(default("a"), literal("it's"));
+    assert_eq!(default("b"), literal(""));
+    assert_eq!(default("c"), literal(""));
+    assert_eq!(default("d"), literal("say \"hi\""));
+    assert_eq!(default("e"), literal("a \"quoted\" word"));
+    assert_eq!(default("f"), literal("a::b"));
+    assert_eq!(default("g"), literal(" ( padded ) "));
+    assert_eq!(default("h"), Some(DefaultValue::Binary(vec![0xDE, 0xAD, 0xBE, 0xEF])));
+    assert_eq!(default("i"), Some(DefaultValue::Binary(Vec::new())));
+    assert_eq!(default("j"), Some(DefaultValue::Expression("abs(-1)".to_string())));
+    assert_eq!(default("k"), Some(DefaultValue::Expression("'a' || 'b'".to_string())));
+    assert_eq!(default("l"), literal("+5"));
+    assert_eq!(default("m"), literal("31"));
+    assert_eq!(default("n"), Some(DefaultValue::Null));
+    assert_eq!(default("o"), Some(DefaultValue::Null));
+}
+
+#[test]
+fn explicit_null_defaults_must_be_distinguishable_from_no_default() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Note" (
+            a TEXT DEFAULT NULL,
+            b TEXT,
+            c TEXT NOT NULL DEFAULT NULL,
+            d TEXT NOT NULL
+        );
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Note").unwrap();
+    let default = |name: &str| table.column(name).unwrap().default.clone();
+    assert_eq!(default("a"), Some(DefaultValue::Null));
+    assert_eq!(default("b"), None);
+    assert_eq!(default("c"), Some(DefaultValue::Null));
+    assert_eq!(default("d"), None);
+}
+
+#[test]
+fn column_ordinal_positions_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Book" (
+            c TEXT,
+            a TEXT,
+            b TEXT
+        );
+        ALTER TABLE "{0}"."Book" ADD COLUMN d TEXT;
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let table = result.table("Book").unwrap();
+    let columns: Vec<(&str, Option<u32>)> = table
+        .columns
+        .iter()
+        .map(|c| (c.name.as_ref(), c.ordinal_position))
+        .collect();
+    assert_eq!(
+        columns,
+        vec![("c", Some(1)), ("a", Some(2)), ("b", Some(3)), ("d", Some(4))]
+    );
+}
+
+#[test]
+fn autoincrement_must_be_distinguishable_from_rowid_aliases() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Plain" (id INTEGER, n INTEGER);
+        CREATE TABLE "{0}"."Alias" (id INTEGER PRIMARY KEY, n INTEGER);
+        CREATE TABLE "{0}"."Strict" (id INTEGER PRIMARY KEY AUTOINCREMENT, n INTEGER);
+        CREATE TABLE "{0}"."Int" (id INT PRIMARY KEY);
+        CREATE TABLE "{0}"."Descending" (id INTEGER PRIMARY KEY DESC);
+        CREATE TABLE "{0}"."WithoutRowid" (id INTEGER PRIMARY KEY) WITHOUT ROWID;
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let column = |table: &str, column: &str| result.table(table).unwrap().column(column).unwrap().clone();
+    assert!(!column("Plain", "id").auto_increment);
+    assert!(column("Alias", "id").auto_increment);
+    assert!(!column("Alias", "n").auto_increment);
+    assert!(column("Strict", "id").auto_increment);
+    assert!(!column("Int", "id").auto_increment);
+    assert!(!column("Descending", "id").auto_increment);
+    assert!(!column("WithoutRowid", "id").auto_increment);
+
+    // Only AUTOINCREMENT keeps a counter in sqlite_sequence.
+    assert_eq!(column("Alias", "id").sequence, None);
+    let sequence = column("Strict", "id").sequence.unwrap();
+    assert_eq!(sequence.name, "Strict");
+    assert_eq!(
+        sequence.owned_by,
+        Some(SequenceOwner {
+            table: "Strict".to_string(),
+            column: "id".to_string(),
+        })
+    );
+    assert_eq!(result.sequences, vec![sequence.clone()]);
+    assert_eq!(result.sequence_for_column("Strict", "id"), Some(&sequence));
+    assert!(!result.has_table("sqlite_sequence"));
+}
+
+#[test]
+fn autoincrement_counters_must_work() {
+    let sql = format!(
+        r#"
+        CREATE TABLE "{0}"."Order" (id INTEGER PRIMARY KEY AUTOINCREMENT, n INTEGER);
+        CREATE TABLE "{0}"."Empty" (id INTEGER PRIMARY KEY AUTOINCREMENT);
+        CREATE TABLE "{0}"."Alias" (id INTEGER PRIMARY KEY);
+        INSERT INTO "{0}"."Order" (n) VALUES (1), (2), (3);
+        DELETE FROM "{0}"."Order" WHERE id = 3;
+        INSERT INTO "{0}"."Alias" (id) VALUES (1);
+        "#,
+        SCHEMA
+    );
+    let options = IntrospectionOptions {
+        autoincrement_counters: true,
+        ..IntrospectionOptions::default()
+    };
+    let inspector = setup_sql_with_options(&sql, options);
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let sequence = |table: &str| result.table(table).unwrap().column("id").unwrap().sequence.clone();
+    // Deleted rows do not reset the counter.
+    assert_eq!(sequence("Order").unwrap().last_value, Some(3));
+    assert_eq!(sequence("Empty").unwrap().last_value, None);
+    assert_eq!(sequence("Alias"), None);
+    assert_eq!(result.get_sequence("Order").unwrap().last_value, Some(3));
+    assert!(!result.has_table("sqlite_sequence"));
+
+    let inspector = setup_sql(&sql);
+    let result = inspector.introspect(&SCHEMA.to_string());
+    assert_eq!(result.get_sequence("Order").unwrap().last_value, None);
+}
+
+#[test]
+fn temporary_tables_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Durable" (id INTEGER PRIMARY KEY);
+        CREATE TEMP TABLE "Scratch" (id INTEGER PRIMARY KEY);
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    assert_eq!(
+        result.table("Durable").unwrap().persistence,
+        TablePersistence::Permanent
+    );
+    assert!(!result.has_table("Scratch"));
+
+    let result = inspector.introspect(&"temp".to_string());
+
+    assert_eq!(
+        result.table("Scratch").unwrap().persistence,
+        TablePersistence::Temporary
+    );
+    assert!(!result.has_table("Durable"));
+}
+
+#[test]
+fn without_rowid_tables_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Rowid" (id INTEGER PRIMARY KEY, name TEXT);
+        CREATE TABLE "{0}"."Membership" (
+            user_id INTEGER NOT NULL,
+            group_id INTEGER NOT NULL,
+            role TEXT,
+            CONSTRAINT membership_pk PRIMARY KEY (group_id, user_id)
+        ) WITHOUT ROWID;
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    assert!(!result.table("Rowid").unwrap().without_rowid);
+    let membership = result.table("Membership").unwrap();
+    assert!(membership.without_rowid);
+    assert_eq!(
+        membership.primary_key,
+        Some(PrimaryKey {
+            columns: vec!["group_id".to_string(), "user_id".to_string()],
+            constraint_name: Some("membership_pk".to_string()),
+        })
+    );
+    // The primary key index the table is stored in is not an index of its own.
+    assert_eq!(membership.indexes, vec![]);
+}
+
+#[test]
+fn virtual_tables_must_work() {
+    let sql = format!(
+        r#"
+        CREATE VIRTUAL TABLE "{0}"."docs" USING fts5(title, body, tokenize = 'porter');
+        CREATE VIRTUAL TABLE "{0}"."shapes" USING rtree(id, min_x, max_x);
+        CREATE TABLE "{0}"."docs_archive" (id INTEGER PRIMARY KEY);
+        "#,
+        SCHEMA
+    );
+    let inspector = setup_sql(&sql);
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    assert_eq!(
+        result.virtual_tables,
+        vec![
+            VirtualTable {
+                name: "docs".to_string(),
+                module: "fts5".to_string(),
+                arguments: vec![
+                    "title".to_string(),
+                    "body".to_string(),
+                    "tokenize = 'porter'".to_string()
+                ],
+            },
+            VirtualTable {
+                name: "shapes".to_string(),
+                module: "rtree".to_string(),
+                arguments: vec!["id".to_string(), "min_x".to_string(), "max_x".to_string()],
+            },
+        ]
+    );
+    let table_names: Vec<&str> = result.tables.iter().map(|t| t.name.as_ref()).collect();
+    assert_eq!(table_names, vec!["docs_archive"]);
+
+    let options = IntrospectionOptions {
+        include_shadow_tables: true,
+        ..IntrospectionOptions::default()
+    };
+    let result = setup_sql_with_options(&sql, options).introspect(&SCHEMA.to_string());
+
+    assert!(result.has_table("docs_data"));
+    assert!(result.has_table("shapes_node"));
+    assert!(!result.has_table("docs"));
+}
+
+#[test]
+fn triggers_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Post" (id INTEGER PRIMARY KEY, title TEXT, edits INTEGER, created TEXT);
+        CREATE TABLE "{0}"."Log" (message TEXT);
+        CREATE TRIGGER "{0}"."count_edits" AFTER UPDATE OF title ON "Post"
+        FOR EACH ROW WHEN (new.title <> old.title)
+        BEGIN
+            UPDATE "Post" SET edits = CASE WHEN edits IS NULL THEN 1 ELSE edits + 1 END WHERE id = new.id;
+        END;
+        CREATE TRIGGER "{0}"."stamp" INSERT ON "Post" BEGIN
+            UPDATE "Post" SET created = datetime('now') WHERE id = new.id;
+            INSERT INTO "Log" (message) VALUES ('created');
+        END;
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let post = result.table("Post").unwrap();
+    assert_eq!(
+        post.triggers,
+        vec![
+            Trigger {
+                name: "count_edits".to_string(),
+                timing: TriggerTiming::After,
+                events: vec![TriggerEvent::Update],
+                condition: Some("(new.title <> old.title)".to_string()),
+                body:
+                    r#"UPDATE "Post" SET edits = CASE WHEN edits IS NULL THEN 1 ELSE edits + 1 END WHERE id = new.id;"#
+                        .to_string(),
+            },
+            Trigger {
+                name: "stamp".to_string(),
+                // BEFORE is the default.
+                timing: TriggerTiming::Before,
+                events: vec![TriggerEvent::Insert],
+                condition: None,
+                body: r#"UPDATE "Post" SET created = datetime('now') WHERE id = new.id;
+            INSERT INTO "Log" (message) VALUES ('created');"#
+                    .to_string(),
+            },
+        ]
+    );
+    assert_eq!(result.table("Log").unwrap().triggers, vec![]);
+}
+
+#[test]
+fn triggers_must_ignore_the_case_of_the_table_name() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Post" (id INTEGER PRIMARY KEY, edits INTEGER);
+        CREATE TRIGGER "{0}"."count_edits" AFTER UPDATE ON post BEGIN
+            UPDATE post SET edits = edits + 1 WHERE id = new.id;
+        END;
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    let triggers = &result.table("Post").unwrap().triggers;
+    assert_eq!(triggers.len(), 1);
+    assert_eq!(triggers[0].name, "count_edits");
+}
+
+#[test]
+fn view_triggers_must_work() {
+    let inspector = setup_sql(&format!(
+        r#"
+        CREATE TABLE "{0}"."Item" (a INTEGER);
+        CREATE VIEW "{0}"."ItemView" AS SELECT a FROM "Item";
+        CREATE TRIGGER "{0}"."insert_item" INSTEAD OF INSERT ON "ItemView" BEGIN
+            INSERT INTO "Item" (a) VALUES (new.a);
+        END;
+        "#,
+        SCHEMA
+    ));
+
+    let result = inspector.introspect(&SCHEMA.to_string());
+
+    assert_eq!(
+        result.get_view("ItemView").unwrap().triggers,
+        vec![Trigger {
+            name: "insert_item".to_string(),
+            timing: TriggerTiming::InsteadOf,
+            events: vec![TriggerEvent::Insert],
+            condition: None,
+            body: r#"INSERT INTO "Item" (a) VALUES (new.a);"#.to_string(),
+        }]
+    );
+    assert_eq!(result.table("Item").unwrap().triggers, vec![]);
+}
+
 fn setup<F>(mut migrationFn: F) -> Box<DatabaseInspector>
 where
     F: FnMut(&mut Migration) -> (),
 {
-    let connection = Connection::open_in_memory()
+    let mut migration = Migration::new().schema(SCHEMA);
+    migrationFn(&mut migration);
+    let full_sql = migration.make::<Squirrel>();
+    let connection = connection();
+    for sql in full_sql.split(";") {
+        dbg!(sql);
+        if !sql.is_empty() {
+            connection.execute(&sql, NO_PARAMS).unwrap();
+        }
+    }
+
+    Box::new(DatabaseInspectorImpl::new(connection))
+}
+
+fn setup_sql(sql: &str) -> Box<dyn DatabaseInspector> {
+    setup_sql_with_options(sql, IntrospectionOptions::default())
+}
+
+fn setup_sql_with_options(sql: &str, options: IntrospectionOptions) -> Box<dyn DatabaseInspector> {
+    let connection = connection();
+    connection.execute_batch(sql).unwrap();
+
+    Box::new(DatabaseInspectorImpl::with_options(connection, options))
+}
+
+fn connection() -> Connection {
+    Connection::open_in_memory()
         .and_then(|c| {
             let server_root = std::env::var("SERVER_ROOT").expect("Env var SERVER_ROOT required but not found.");
             let path = format!("{}/db", server_root);
@@ -145,19 +1698,5 @@ where
             c.execute("ATTACH DATABASE ? AS ?", &[database_file_path.as_ref(), SCHEMA])
                 .map(|_| c)
         })
-        .and_then(|c| {
-            let mut migration = Migration::new().schema(SCHEMA);
-            migrationFn(&mut migration);
-            let full_sql = migration.make::<Squirrel>();
-            for sql in full_sql.split(";") {
-                dbg!(sql);
-                if (sql != "") {
-                    c.execute(&sql, NO_PARAMS).unwrap();
-                }
-            }
-            Ok(c)
-        })
-        .unwrap();
-
-    Box::new(DatabaseInspectorImpl::new(connection))
+        .unwrap()
 }
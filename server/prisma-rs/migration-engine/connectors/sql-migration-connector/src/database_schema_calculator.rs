@@ -22,7 +22,13 @@ impl<'a> DatabaseSchemaCalculator<'a> {
         tables.append(&mut scalar_list_tables);
         tables.append(&mut relation_tables);
 
-        DatabaseSchema { tables }
+        DatabaseSchema {
+            tables,
+            views: Vec::new(),
+            enums: Vec::new(),
+            sequences: Vec::new(),
+            virtual_tables: Vec::new(),
+        }
     }
 
     fn calculate_model_tables(&self) -> Vec<Table> {
@@ -32,21 +38,15 @@ impl<'a> DatabaseSchemaCalculator<'a> {
                 let columns = model
                     .fields()
                     .flat_map(|f| match (&f.field_type, &f.arity) {
-                        (FieldType::Base(scalar), arity) if arity != &FieldArity::List => Some(Column {
-                            name: f.name.clone(),
-                            tpe: column_type(scalar),
-                            is_required: arity == &FieldArity::Required,
-                            foreign_key: None,
-                            sequence: None,
-                        }),
+                        (FieldType::Base(scalar), arity) if arity != &FieldArity::List => Some(Column::new(
+                            f.name.clone(),
+                            column_type(scalar),
+                            arity == &FieldArity::Required,
+                        )),
                         _ => None,
                     })
                     .collect();
-                Table {
-                    name: model.name.clone(),
-                    columns: columns,
-                    indexes: Vec::new(),
-                }
+                Table::new(model.name.clone(), columns)
             })
             .collect()
     }
@@ -61,15 +61,14 @@ impl<'a> DatabaseSchemaCalculator<'a> {
                 .collect();
             for field in list_fields {
                 let id_field = id_field(&model); // todo: find actual id field
-                let table = Table {
-                    name: format!("{}_{}", model.name.clone(), field.name.clone()),
-                    columns: vec![
+                let table = Table::new(
+                    format!("{}_{}", model.name.clone(), field.name.clone()),
+                    vec![
                         Column::new("nodeId".to_string(), column_type(&scalar_type(&id_field)), true),
-                        Column::new("position".to_string(), ColumnType::Int, true),
+                        Column::new("position".to_string(), ColumnType::pure(ColumnTypeFamily::Int), true),
                         Column::new("value".to_string(), column_type(&scalar_type(&field)), true),
                     ],
-                    indexes: Vec::new(),
-                };
+                );
                 result.push(table);
             }
         }
@@ -85,9 +84,9 @@ impl<'a> DatabaseSchemaCalculator<'a> {
                     model_a_column,
                     model_b_column,
                 } if relation.is_many_to_many() => {
-                    let table = Table {
-                        name: relation.table_name(),
-                        columns: vec![
+                    let table = Table::new(
+                        relation.table_name(),
+                        vec![
                             Column::with_foreign_key(
                                 model_a_column.to_string(),
                                 column_type(&scalar_type(id_field(&relation.model_a))),
@@ -95,6 +94,9 @@ impl<'a> DatabaseSchemaCalculator<'a> {
                                 ForeignKey {
                                     table: relation.model_a.name.to_string(),
                                     column: id_field(&relation.model_a).name.to_string(),
+                                    constraint_name: None,
+                                    on_delete_action: ForeignKeyAction::NoAction,
+                                    on_update_action: ForeignKeyAction::NoAction,
                                 },
                             ),
                             Column::with_foreign_key(
@@ -104,11 +106,13 @@ impl<'a> DatabaseSchemaCalculator<'a> {
                                 ForeignKey {
                                     table: relation.model_b.name.to_string(),
                                     column: id_field(&relation.model_b).name.to_string(),
+                                    constraint_name: None,
+                                    on_delete_action: ForeignKeyAction::NoAction,
+                                    on_update_action: ForeignKeyAction::NoAction,
                                 },
                             ),
                         ],
-                        indexes: Vec::new(),
-                    };
+                    );
                     result.push(table);
                 }
                 _ => {}
@@ -241,13 +245,14 @@ fn scalar_type(field: &Field) -> &ScalarType {
 }
 
 fn column_type(scalar_type: &ScalarType) -> ColumnType {
-    match scalar_type {
-        ScalarType::Int => ColumnType::Int,
-        ScalarType::Float => ColumnType::Float,
-        ScalarType::Boolean => ColumnType::Boolean,
-        ScalarType::Enum => ColumnType::String,
-        ScalarType::String => ColumnType::String,
-        ScalarType::DateTime => ColumnType::DateTime,
-        ScalarType::Decimal => unimplemented!(),
-    }
+    let family = match scalar_type {
+        ScalarType::Int => ColumnTypeFamily::Int,
+        ScalarType::Float => ColumnTypeFamily::Float,
+        ScalarType::Boolean => ColumnTypeFamily::Boolean,
+        ScalarType::Enum => ColumnTypeFamily::String,
+        ScalarType::String => ColumnTypeFamily::String,
+        ScalarType::DateTime => ColumnTypeFamily::DateTime,
+        ScalarType::Decimal => ColumnTypeFamily::Decimal,
+    };
+    ColumnType::pure(family)
 }
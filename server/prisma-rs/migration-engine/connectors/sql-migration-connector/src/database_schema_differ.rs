@@ -35,7 +35,7 @@ impl DatabaseSchemaDiffer {
                     .indexes
                     .iter()
                     .find(|i| i.unique)
-                    .map(|i| i.columns.clone())
+                    .map(|i| i.column_names())
                     .unwrap_or(Vec::new());
 
                 let create = CreateTable {
@@ -113,7 +113,7 @@ impl DatabaseSchemaDiffer {
         let mut result = Vec::new();
         for next_column in &next.columns {
             if let Some(previous_column) = previous.column(&next_column.name) {
-                if previous_column != next_column {
+                if Self::column_changed(previous_column, next_column) {
                     let change = AlterColumn {
                         name: previous_column.name.clone(),
                         column: Self::column_description(next_column),
@@ -125,6 +125,17 @@ impl DatabaseSchemaDiffer {
         result
     }
 
+    /// Compares the type family instead of the whole type, the calculated schema has no raw database types. It has no
+    /// foreign key constraint names either.
+    fn column_changed(previous: &Column, next: &Column) -> bool {
+        previous.tpe.family != next.tpe.family
+            || previous.is_required != next.is_required
+            || match (&previous.foreign_key, &next.foreign_key) {
+                (Some(previous), Some(next)) => !previous.is_equivalent_to(next),
+                (previous, next) => previous.is_some() != next.is_some(),
+            }
+    }
+
     fn column_descriptions(columns: &Vec<Column>) -> Vec<ColumnDescription> {
         columns.iter().map(Self::column_description).collect()
     }
@@ -132,18 +143,27 @@ impl DatabaseSchemaDiffer {
     fn column_description(column: &Column) -> ColumnDescription {
         ColumnDescription {
             name: column.name.clone(),
-            tpe: Self::convert_column_type(column.tpe),
+            tpe: Self::convert_column_type(&column.tpe.family),
             required: column.is_required,
         }
     }
 
-    fn convert_column_type(inspector_type: database_inspector::ColumnType) -> ColumnType {
+    fn convert_column_type(inspector_type: &database_inspector::ColumnTypeFamily) -> ColumnType {
         match inspector_type {
-            database_inspector::ColumnType::Boolean => ColumnType::Boolean,
-            database_inspector::ColumnType::Int => ColumnType::Int,
-            database_inspector::ColumnType::Float => ColumnType::Float,
-            database_inspector::ColumnType::String => ColumnType::String,
-            database_inspector::ColumnType::DateTime => ColumnType::DateTime,
+            database_inspector::ColumnTypeFamily::Boolean => ColumnType::Boolean,
+            database_inspector::ColumnTypeFamily::Int => ColumnType::Int,
+            database_inspector::ColumnTypeFamily::BigInt => ColumnType::Int,
+            database_inspector::ColumnTypeFamily::Float => ColumnType::Float,
+            database_inspector::ColumnTypeFamily::Double => ColumnType::Float,
+            database_inspector::ColumnTypeFamily::Decimal => ColumnType::Decimal,
+            database_inspector::ColumnTypeFamily::String => ColumnType::String,
+            database_inspector::ColumnTypeFamily::DateTime => ColumnType::DateTime,
+            // Enums are stored as strings, see the schema calculator.
+            database_inspector::ColumnTypeFamily::Enum(_) => ColumnType::String,
+            // Only introspected columns have these families, the schema calculator never produces them.
+            database_inspector::ColumnTypeFamily::Money => ColumnType::Float,
+            database_inspector::ColumnTypeFamily::Binary => ColumnType::String,
+            database_inspector::ColumnTypeFamily::Unsupported(_) => ColumnType::String,
         }
     }
 }
@@ -63,6 +63,8 @@ fn column_description_to_barrel_type(column_description: &ColumnDescription) ->
         ColumnType::Boolean => barrel::types::boolean(),
         ColumnType::DateTime => barrel::types::date(),
         ColumnType::Float => barrel::types::float(),
+        // Created with a type that is introspected as decimal again, REAL would turn it into a float.
+        ColumnType::Decimal => barrel::types::custom("DECIMAL"),
         ColumnType::Int => barrel::types::integer(),
         ColumnType::String => barrel::types::text(),
     };
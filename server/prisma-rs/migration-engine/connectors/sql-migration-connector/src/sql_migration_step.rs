@@ -63,6 +63,7 @@ pub struct ColumnDescription {
 pub enum ColumnType {
     Int,
     Float,
+    Decimal,
     Boolean,
     String,
     DateTime,
@@ -0,0 +1,41 @@
+#![allow(non_snake_case)]
+
+mod test_harness;
+
+use datamodel::dml::*;
+use datamodel::validator::Validator;
+use migration_connector::*;
+use test_harness::*;
+
+#[test]
+fn Decimal_fields_must_not_produce_steps_once_applied() {
+    run_test(|| {
+        let connector = connector();
+        let datamodel = parse(
+            r#"
+            model Product {
+                id: Int @primary
+                price: Decimal
+            }
+        "#,
+        );
+
+        let steps = connector
+            .database_steps_inferrer()
+            .infer(&Schema::empty(), &datamodel, Vec::new());
+        for step in steps {
+            connector.database_step_applier().apply(step);
+        }
+
+        let steps = connector
+            .database_steps_inferrer()
+            .infer(&datamodel, &datamodel, Vec::new());
+        assert!(steps.is_empty(), "{:?}", steps);
+    });
+}
+
+fn parse(datamodel_string: &'static str) -> Schema {
+    let ast = datamodel::parser::parse(datamodel_string).unwrap();
+    let validator = Validator::new();
+    validator.validate(&ast).unwrap()
+}